@@ -0,0 +1,69 @@
+// Copyright 2025 RISC Zero, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Decodes arbitrary words as the input of a guest and runs its entry point
+//! natively. Whatever the words, decoding must fail or the entry point must
+//! return, committing a result or a structured failure, without panicking or
+//! exhausting memory.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use risc0_benchmark_lib::{
+    entry::{self, BTreeInput, GemmInput, MaxflowInput, SortInput},
+    montecarlo::OptionParams,
+    MembershipProof, Sudoku,
+};
+use risc0_zkvm::serde::from_slice;
+use serde::de::DeserializeOwned;
+
+/// The most paths times steps a pricing run may simulate, counting each path
+/// as a step of its own. Longer runs are valid but only time the fuzzer out.
+const MAX_STEPS: u64 = 1 << 16;
+
+/// Decodes `words` as a `T` the way a guest reads its input, then runs
+/// `entry` on it.
+fn run<T: DeserializeOwned, R>(words: &[u32], entry: impl FnOnce(T) -> R) {
+    if let Ok(input) = from_slice::<T, _>(words) {
+        entry(input);
+    }
+}
+
+fuzz_target!(|data: &[u8]| {
+    let Some((&guest, bytes)) = data.split_first() else {
+        return;
+    };
+    let words: Vec<u32> = bytes
+        .chunks(4)
+        .map(|chunk| {
+            let mut word = [0; 4];
+            word[..chunk.len()].copy_from_slice(chunk);
+            u32::from_le_bytes(word)
+        })
+        .collect();
+
+    match guest % 7 {
+        0 => run::<BTreeInput, _>(&words, entry::btree),
+        1 => run::<GemmInput, _>(&words, entry::gemm),
+        2 => run::<MaxflowInput, _>(&words, entry::maxflow),
+        3 => run::<MembershipProof, _>(&words, entry::membership),
+        4 => run::<OptionParams, _>(&words, |params| {
+            if params.paths as u64 * (params.steps as u64 + 1) <= MAX_STEPS {
+                let _ = entry::montecarlo(params);
+            }
+        }),
+        5 => run::<SortInput, _>(&words, entry::sort),
+        _ => run::<Sudoku, _>(&words, entry::sudoku),
+    }
+});
@@ -0,0 +1,37 @@
+// Copyright 2025 RISC Zero, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risc0_benchmark_lib::failure::{fail, ErrorCode};
+use risc0_zkvm::{
+    guest::env,
+    sha::{self, Digest, Sha256},
+};
+
+fn main() {
+    // An optional digest of a public randomness beacon, followed by the image
+    // ID and journal of each receipt to aggregate. The receipts themselves are
+    // supplied by the host as assumptions.
+    let (beacon, receipts): (Option<Digest>, Vec<(Digest, Vec<u8>)>) = env::read();
+
+    let summary: Vec<(Digest, Digest)> = receipts
+        .iter()
+        .enumerate()
+        .map(|(i, (image_id, journal))| {
+            env::verify(*image_id, journal)
+                .unwrap_or_else(|_| fail(ErrorCode::InvalidReceipt, i as u32));
+            (*image_id, *sha::Impl::hash_bytes(journal))
+        })
+        .collect();
+    env::commit(&(beacon, summary));
+}
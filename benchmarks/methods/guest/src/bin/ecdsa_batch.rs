@@ -0,0 +1,43 @@
+// Copyright 2025 RISC Zero, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![no_main]
+
+use k256::{
+    ecdsa::{signature::Verifier, Signature, VerifyingKey},
+    EncodedPoint,
+};
+use risc0_zkvm::{guest::env, sha, sha::Sha256};
+
+risc0_zkvm::guest::entry!(main);
+
+fn main() {
+    let batch: Vec<(EncodedPoint, Vec<u8>, Signature)> = env::read();
+
+    // Count the valid signatures instead of panicking on the first invalid one,
+    // and hash each message so the journal binds the whole message set.
+    let mut valid = 0u32;
+    let mut message_hashes = Vec::with_capacity(batch.len() * 32);
+    for (encoded_verifying_key, message, signature) in batch.iter() {
+        let verified = VerifyingKey::from_encoded_point(encoded_verifying_key)
+            .is_ok_and(|key| key.verify(message, signature).is_ok());
+        if verified {
+            valid += 1;
+        }
+        message_hashes.extend_from_slice(sha::Impl::hash_bytes(message).as_bytes());
+    }
+
+    let messages_digest = sha::Impl::hash_bytes(&message_hashes);
+    env::commit(&(batch.len() as u32, valid, *messages_digest));
+}
@@ -18,6 +18,10 @@ use k256::{
     ecdsa::{signature::Verifier, Signature, VerifyingKey},
     EncodedPoint,
 };
+use risc0_benchmark_lib::{
+    failure::{fail, ErrorCode},
+    guest_assert,
+};
 use risc0_zkvm::guest::env;
 
 risc0_zkvm::guest::entry!(main);
@@ -30,13 +34,17 @@ fn main() {
         Vec<u8>,
         Signature,
     ) = env::read();
-    let verifying_key = VerifyingKey::from_encoded_point(&encoded_verifying_key).unwrap();
+    let verifying_key = VerifyingKey::from_encoded_point(&encoded_verifying_key)
+        .unwrap_or_else(|_| fail(ErrorCode::InvalidKey, 0));
 
-    // Verify the signature, panicking if verification fails.
-    for _ in 0..iterations {
-        verifying_key
-            .verify(&message, &signature)
-            .expect("ECDSA signature verification failed");
+    // Verify the signature, failing with the iteration at which it did not
+    // verify.
+    for i in 0..iterations {
+        guest_assert!(
+            verifying_key.verify(&message, &signature).is_ok(),
+            ErrorCode::InvalidSignature,
+            i
+        );
     }
 
     // Commit to the journal the verifying key and message that was signed.
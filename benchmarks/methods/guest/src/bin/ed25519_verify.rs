@@ -15,6 +15,10 @@
 #![no_main]
 
 use ed25519_dalek::{Signature, Verifier, VerifyingKey};
+use risc0_benchmark_lib::{
+    failure::{fail, ErrorCode, UNDECODED},
+    guest_assert,
+};
 use risc0_zkvm::guest::env;
 
 risc0_zkvm::guest::entry!(main);
@@ -27,13 +31,19 @@ fn main() {
         Vec<u8>,
         Vec<u8>,
     ) = env::read();
-    let verifying_key = VerifyingKey::from_bytes(&encoded_verifying_key).unwrap();
-    let signature: Signature = Signature::from_slice(&signature_bytes).unwrap();
-    // Verify the signature, panicking if verification fails.
-    for _ in 0..iterations {
-        verifying_key
-            .verify(&message, &signature)
-            .expect("Ed25519 signature verification failed");
+    let verifying_key = VerifyingKey::from_bytes(&encoded_verifying_key)
+        .unwrap_or_else(|_| fail(ErrorCode::InvalidKey, 0));
+    let signature = Signature::from_slice(&signature_bytes)
+        .unwrap_or_else(|_| fail(ErrorCode::InvalidSignature, UNDECODED));
+
+    // Verify the signature, failing with the iteration at which it did not
+    // verify.
+    for i in 0..iterations {
+        guest_assert!(
+            verifying_key.verify(&message, &signature).is_ok(),
+            ErrorCode::InvalidSignature,
+            i
+        );
     }
 
     // Commit to the journal the verifying key and message that was signed.
@@ -0,0 +1,54 @@
+// Copyright 2025 RISC Zero, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use png::{ColorType, Decoder, Transformations};
+use risc0_benchmark_lib::{
+    failure::{fail, ErrorCode},
+    imgdec::{dhash, rgb_to_luma},
+};
+use risc0_zkvm::guest::env;
+
+fn main() {
+    let image: Vec<u8> = env::read();
+
+    let mut decoder = Decoder::new(image.as_slice());
+    decoder.set_transformations(Transformations::normalize_to_color8());
+    // The context is 0 for an invalid header and 1 for invalid image data.
+    let mut reader = decoder
+        .read_info()
+        .unwrap_or_else(|_| fail(ErrorCode::InvalidImage, 0));
+    let mut pixels = vec![0; reader.output_buffer_size()];
+    let info = reader
+        .next_frame(&mut pixels)
+        .unwrap_or_else(|_| fail(ErrorCode::InvalidImage, 1));
+    let pixels = &pixels[..info.buffer_size()];
+
+    let luma = match info.color_type {
+        ColorType::Grayscale => pixels.to_vec(),
+        ColorType::GrayscaleAlpha => pixels.iter().step_by(2).copied().collect(),
+        ColorType::Rgb => rgb_to_luma(pixels),
+        ColorType::Rgba => {
+            let rgb: Vec<u8> = pixels
+                .chunks_exact(4)
+                .flat_map(|px| [px[0], px[1], px[2]])
+                .collect();
+            rgb_to_luma(&rgb)
+        }
+        ColorType::Indexed => unreachable!("palettes are expanded by the decoder"),
+    };
+
+    // Only the dimensions and the perceptual hash are revealed.
+    let hash = dhash(info.width as usize, info.height as usize, &luma);
+    env::commit(&(info.width, info.height, hash));
+}
@@ -0,0 +1,34 @@
+// Copyright 2025 RISC Zero, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risc0_benchmark_lib::failure::{fail, ErrorCode};
+use risc0_zkvm::{guest::env, sha, sha::Sha256};
+use serde_json::Value;
+
+fn main() {
+    let (document, queries): (String, Vec<String>) = env::read();
+    let value = serde_json::from_str::<Value>(&document)
+        .unwrap_or_else(|_| fail(ErrorCode::InvalidDocument, 0));
+
+    // Each query is a JSON pointer; missing values are recorded as `null`.
+    let mut results = Vec::new();
+    for query in queries.iter() {
+        let extracted = value.pointer(query).unwrap_or(&Value::Null);
+        serde_json::to_writer(&mut results, extracted).unwrap();
+        results.push(b'\n');
+    }
+
+    let digest = sha::Impl::hash_bytes(&results);
+    env::commit(&(queries.len() as u32, *digest));
+}
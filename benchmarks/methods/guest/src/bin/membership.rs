@@ -12,11 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use risc0_benchmark_lib::MembershipProof;
+use risc0_benchmark_lib::entry::{self, commit_or_fail};
 use risc0_zkvm::guest::env;
 
 fn main() {
-    let proof: MembershipProof = env::read();
-    assert!(proof.verify());
-    env::commit(&(proof.leaf, proof.root))
+    commit_or_fail(entry::membership(env::read()));
 }
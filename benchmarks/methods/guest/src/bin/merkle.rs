@@ -0,0 +1,49 @@
+// Copyright 2025 RISC Zero, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risc0_benchmark_lib::{
+    failure::{fail, ErrorCode},
+    merkle::{software_sha256, MerkleBuilder},
+};
+use risc0_zkvm::{
+    guest::env,
+    sha::{self, Digest, Sha256},
+};
+
+fn accelerated_sha256(data: &[u8]) -> [u8; 32] {
+    sha::Impl::hash_bytes(data).as_bytes().try_into().unwrap()
+}
+
+fn main() {
+    let (accelerated, chunk_size, num_chunks): (bool, u32, u32) = env::read();
+
+    let mut builder = MerkleBuilder::new(if accelerated {
+        accelerated_sha256
+    } else {
+        software_sha256
+    });
+
+    // Stream the chunks one at a time so the input never has to be held in
+    // memory all at once.
+    let mut chunk = vec![0u8; chunk_size as usize];
+    for _ in 0..num_chunks {
+        env::read_slice(&mut chunk);
+        builder.push_leaf(&chunk);
+    }
+
+    let root = builder
+        .finalize()
+        .unwrap_or_else(|| fail(ErrorCode::NoChunks, 0));
+    env::commit(&(num_chunks, Digest::from(root)));
+}
@@ -0,0 +1,37 @@
+// Copyright 2025 RISC Zero, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use num_bigint::BigUint;
+use risc0_bigint2::{field::FIELD_4096_WIDTH_WORDS, ToBigInt2Buffer};
+use risc0_zkvm::guest::env;
+
+fn main() {
+    let (accelerated, base, modulus): (bool, BigUint, BigUint) = env::read();
+
+    // Both paths compute base^65537 mod modulus, so the committed result is
+    // identical regardless of which one is selected.
+    let result = if accelerated {
+        let mut result = [0u32; FIELD_4096_WIDTH_WORDS];
+        risc0_bigint2::rsa::modpow_65537(
+            &base.to_u32_array(),
+            &modulus.to_u32_array(),
+            &mut result,
+        );
+        BigUint::from_slice(&result)
+    } else {
+        base.modpow(&BigUint::from(65537u32), &modulus)
+    };
+
+    env::commit(&result);
+}
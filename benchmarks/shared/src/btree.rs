@@ -0,0 +1,295 @@
+// Copyright 2025 RISC Zero, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use serde::{Deserialize, Serialize};
+
+/// A node of a [BTreeIndex].
+#[derive(Serialize, Deserialize, Debug)]
+pub enum BTreeNode {
+    /// An internal node, where `children[i]` holds keys smaller than `keys[i]`
+    /// and `children.len() == keys.len() + 1`.
+    Internal { keys: Vec<u64>, children: Vec<u32> },
+    /// A leaf node holding sorted entries and a link to the next leaf.
+    Leaf {
+        keys: Vec<u64>,
+        values: Vec<u64>,
+        next: Option<u32>,
+    },
+}
+
+/// A B+ tree index over `u64` keys, stored as a flat list of nodes so that it
+/// can be serialized and traversed in place.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct BTreeIndex {
+    pub nodes: Vec<BTreeNode>,
+    pub root: u32,
+}
+
+/// A query to evaluate against a [BTreeIndex].
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub enum BTreeQuery {
+    /// Look up a single key.
+    Get(u64),
+    /// Return all entries with keys in the half-open range `[start, end)`.
+    Range(u64, u64),
+}
+
+/// The reason a [BTreeIndex] was rejected by [BTreeIndex::validate].
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IndexError {
+    /// The root is not a node of the index.
+    RootOutOfRange,
+    /// The node at the given index has the wrong number of children or values
+    /// for its keys.
+    LengthMismatch { node: u32 },
+    /// The keys of the node at the given index are not strictly increasing.
+    UnsortedKeys { node: u32 },
+    /// A child of the internal node at the given index is not stored before it.
+    ChildOutOfRange { node: u32 },
+    /// The leaf at the given index links to a node that is not a later leaf.
+    NextOutOfRange { node: u32 },
+}
+
+impl BTreeIndex {
+    /// Bulk-loads an index from entries sorted by strictly increasing key,
+    /// with at most `fanout` entries or children per node.
+    pub fn from_sorted(entries: &[(u64, u64)], fanout: usize) -> Self {
+        assert!(fanout >= 2);
+        assert!(entries.windows(2).all(|w| w[0].0 < w[1].0));
+
+        let mut nodes = Vec::new();
+        // Each level is a list of (node index, smallest key) pairs.
+        let mut level = Vec::new();
+        let chunks: Vec<_> = entries.chunks(fanout).collect();
+        for (i, chunk) in chunks.iter().enumerate() {
+            let next = (i + 1 < chunks.len()).then(|| (nodes.len() + 1) as u32);
+            level.push((nodes.len() as u32, chunk[0].0));
+            nodes.push(BTreeNode::Leaf {
+                keys: chunk.iter().map(|(k, _)| *k).collect(),
+                values: chunk.iter().map(|(_, v)| *v).collect(),
+                next,
+            });
+        }
+        if level.is_empty() {
+            level.push((0, 0));
+            nodes.push(BTreeNode::Leaf {
+                keys: Vec::new(),
+                values: Vec::new(),
+                next: None,
+            });
+        }
+
+        while level.len() > 1 {
+            level = level
+                .chunks(fanout)
+                .map(|group| {
+                    nodes.push(BTreeNode::Internal {
+                        keys: group[1..].iter().map(|(_, min)| *min).collect(),
+                        children: group.iter().map(|(idx, _)| *idx).collect(),
+                    });
+                    ((nodes.len() - 1) as u32, group[0].1)
+                })
+                .collect();
+        }
+
+        Self {
+            nodes,
+            root: level[0].0,
+        }
+    }
+
+    /// Checks that the index is well formed, reporting the first violation
+    /// found.
+    ///
+    /// Besides the shape of each node, this requires the layout produced by
+    /// [BTreeIndex::from_sorted]: children are stored before their parent and
+    /// each leaf links to a later leaf. Lookups on a valid index therefore
+    /// always reach a leaf and always terminate, whatever its keys.
+    pub fn validate(&self) -> Result<(), IndexError> {
+        if self.root as usize >= self.nodes.len() {
+            return Err(IndexError::RootOutOfRange);
+        }
+        for (i, node) in self.nodes.iter().enumerate() {
+            let idx = i as u32;
+            let keys = match node {
+                BTreeNode::Internal { keys, children } => {
+                    if children.len() != keys.len() + 1 {
+                        return Err(IndexError::LengthMismatch { node: idx });
+                    }
+                    if children.iter().any(|&child| child >= idx) {
+                        return Err(IndexError::ChildOutOfRange { node: idx });
+                    }
+                    keys
+                }
+                BTreeNode::Leaf { keys, values, next } => {
+                    if values.len() != keys.len() {
+                        return Err(IndexError::LengthMismatch { node: idx });
+                    }
+                    if let Some(next) = *next {
+                        let is_later_leaf = next > idx
+                            && matches!(
+                                self.nodes.get(next as usize),
+                                Some(BTreeNode::Leaf { .. })
+                            );
+                        if !is_later_leaf {
+                            return Err(IndexError::NextOutOfRange { node: idx });
+                        }
+                    }
+                    keys
+                }
+            };
+            if !keys.windows(2).all(|w| w[0] < w[1]) {
+                return Err(IndexError::UnsortedKeys { node: idx });
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the index of the leaf that would contain `key`.
+    fn find_leaf(&self, key: u64) -> u32 {
+        let mut idx = self.root;
+        while let BTreeNode::Internal { keys, children } = &self.nodes[idx as usize] {
+            idx = children[keys.partition_point(|k| *k <= key)];
+        }
+        idx
+    }
+
+    pub fn get(&self, key: u64) -> Option<u64> {
+        match &self.nodes[self.find_leaf(key) as usize] {
+            BTreeNode::Leaf { keys, values, .. } => {
+                keys.binary_search(&key).ok().map(|i| values[i])
+            }
+            BTreeNode::Internal { .. } => unreachable!(),
+        }
+    }
+
+    /// Returns all entries with keys in `[start, end)`, in key order.
+    pub fn range(&self, start: u64, end: u64) -> Vec<(u64, u64)> {
+        let mut result = Vec::new();
+        let mut leaf = Some(self.find_leaf(start));
+        while let Some(idx) = leaf {
+            let BTreeNode::Leaf { keys, values, next } = &self.nodes[idx as usize] else {
+                unreachable!()
+            };
+            for (key, value) in keys.iter().zip(values.iter()) {
+                if *key >= end {
+                    return result;
+                }
+                if *key >= start {
+                    result.push((*key, *value));
+                }
+            }
+            leaf = *next;
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::*;
+    use rand::Rng;
+
+    use crate::rng::{ChaCha12Rng, SeedableRng};
+
+    #[test]
+    fn matches_std_btree_map() {
+        let entries: Vec<(u64, u64)> = (0..1000).map(|i| (i * 3 + i % 2, i * i)).collect();
+        let expected: BTreeMap<u64, u64> = entries.iter().copied().collect();
+
+        for fanout in [2, 3, 16, 2000] {
+            let index = BTreeIndex::from_sorted(&entries, fanout);
+            for key in 0..3100 {
+                assert_eq!(index.get(key), expected.get(&key).copied());
+            }
+            for (start, end) in [(0, 10), (5, 5), (100, 1500), (2990, 4000), (4000, 5000)] {
+                let actual = index.range(start, end);
+                let expected: Vec<_> = expected.range(start..end).map(|(k, v)| (*k, *v)).collect();
+                assert_eq!(actual, expected);
+            }
+        }
+
+        let empty = BTreeIndex::from_sorted(&[], 4);
+        assert_eq!(empty.get(0), None);
+        assert!(empty.range(0, u64::MAX).is_empty());
+    }
+
+    #[test]
+    fn arbitrary_indexes_are_rejected_or_queried() {
+        // A cheap fuzzer: randomly corrupted indexes must either be rejected
+        // by validation or answer queries without panicking or looping.
+        fn set<T>(items: &mut [T], pick: usize, value: T) {
+            if !items.is_empty() {
+                let i = pick % items.len();
+                items[i] = value;
+            }
+        }
+
+        let mut rng = ChaCha12Rng::seed_from_u64(3);
+        let entries: Vec<(u64, u64)> = (0..200).map(|i| (i * 2, i)).collect();
+        let (mut rejected, mut accepted) = (0, 0);
+        for _ in 0..2000 {
+            let mut index = BTreeIndex::from_sorted(&entries, 4);
+            let len = index.nodes.len() as u64;
+            for _ in 0..rng.random_range(1..=3) {
+                let value = match rng.random_range(0..3) {
+                    0 => rng.random_range(0..len + 2),
+                    1 => rng.random_range(0..400),
+                    _ => u64::MAX - rng.random_range(0..2),
+                };
+                if rng.random_range(0..8) == 0 {
+                    index.root = value as u32;
+                    continue;
+                }
+                let (node, pick, action) = (
+                    rng.random_range(0..len),
+                    rng.random::<u32>() as usize,
+                    rng.random_range(0..4),
+                );
+                match &mut index.nodes[node as usize] {
+                    BTreeNode::Internal { keys, children } => match action {
+                        0 => set(keys, pick, value),
+                        1 => set(children, pick, value as u32),
+                        2 => {
+                            children.pop();
+                        }
+                        _ => keys.push(value),
+                    },
+                    BTreeNode::Leaf { keys, values, next } => match action {
+                        0 => set(keys, pick, value),
+                        1 => *next = Some(value as u32),
+                        2 => {
+                            values.pop();
+                        }
+                        _ => *next = None,
+                    },
+                }
+            }
+
+            if index.validate().is_err() {
+                rejected += 1;
+                continue;
+            }
+            for _ in 0..8 {
+                let start = rng.random_range(0..420);
+                index.get(start);
+                index.range(start, start + rng.random_range(0..100));
+            }
+            accepted += 1;
+        }
+        assert!(rejected > 0 && accepted > 0);
+    }
+}
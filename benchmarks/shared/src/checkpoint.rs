@@ -0,0 +1,107 @@
+// Copyright 2025 RISC Zero, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Named checkpoints that a guest reports to the host as it runs, giving a
+//! phase-by-phase cycle breakdown without the overhead of full profiling.
+//!
+//! Each checkpoint is written to [CHECKPOINT_FD] as the current cycle count
+//! (`u64`), the length of the name (`u32`) and the name itself, all
+//! little-endian. Checkpoints go to a host file descriptor rather than the
+//! journal, so they do not change what a guest commits.
+
+/// The guest file descriptor that checkpoints are written to.
+pub const CHECKPOINT_FD: u32 = 100;
+
+/// A point reached by a guest, and the cycle count when it was reached.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Checkpoint {
+    pub name: String,
+    pub cycle: u64,
+}
+
+/// Writes a checkpoint with the given name and the current cycle count to
+/// [CHECKPOINT_FD].
+#[cfg(target_os = "zkvm")]
+pub fn checkpoint(name: &str) {
+    use risc0_zkvm::guest::env::{self, FdWriter, Write};
+
+    let mut record = env::cycle_count().to_le_bytes().to_vec();
+    record.extend_from_slice(&(name.len() as u32).to_le_bytes());
+    record.extend_from_slice(name.as_bytes());
+    FdWriter::new(CHECKPOINT_FD, |_| {}).write_slice(&record);
+}
+
+/// Does nothing, as there is no host to report to when guest code runs
+/// natively, such as under the fuzz target.
+#[cfg(not(target_os = "zkvm"))]
+pub fn checkpoint(_name: &str) {}
+
+/// Records a named checkpoint, see [checkpoint].
+#[macro_export]
+macro_rules! checkpoint {
+    ($name:expr) => {
+        $crate::checkpoint::checkpoint($name)
+    };
+}
+
+/// Decodes the checkpoints written by a guest to [CHECKPOINT_FD].
+///
+/// A truncated trailing record is ignored.
+pub fn parse_checkpoints(mut bytes: &[u8]) -> Vec<Checkpoint> {
+    let mut checkpoints = Vec::new();
+    while bytes.len() >= 12 {
+        let cycle = u64::from_le_bytes(bytes[..8].try_into().unwrap());
+        let len = u32::from_le_bytes(bytes[8..12].try_into().unwrap()) as usize;
+        let Some(name) = bytes.get(12..12 + len) else {
+            break;
+        };
+        checkpoints.push(Checkpoint {
+            name: String::from_utf8_lossy(name).into_owned(),
+            cycle,
+        });
+        bytes = &bytes[12 + len..];
+    }
+    checkpoints
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(name: &str, cycle: u64) -> Vec<u8> {
+        let mut bytes = cycle.to_le_bytes().to_vec();
+        bytes.extend_from_slice(&(name.len() as u32).to_le_bytes());
+        bytes.extend_from_slice(name.as_bytes());
+        bytes
+    }
+
+    #[test]
+    fn parse() {
+        let bytes = [record("read", 10), record("solved", 2500), record("x", 7)].concat();
+        let checkpoints = parse_checkpoints(&bytes[..bytes.len() - 1]);
+        assert_eq!(
+            checkpoints,
+            vec![
+                Checkpoint {
+                    name: "read".into(),
+                    cycle: 10
+                },
+                Checkpoint {
+                    name: "solved".into(),
+                    cycle: 2500
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,257 @@
+// Copyright 2025 RISC Zero, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Entry points of the guests whose logic lives in this crate.
+//!
+//! Each takes the input its guest reads and returns the value the guest
+//! commits, or the [GuestFailure] it reports, so that the same code runs in
+//! the guest and natively under the fuzz target. A guest passes the result to
+//! [commit_or_fail].
+
+use risc0_zkvm::sha::{self, Digest, Sha256};
+
+use crate::{
+    btree::{BTreeIndex, BTreeQuery, IndexError},
+    checkpoint,
+    failure::{ErrorCode, GuestFailure},
+    gemm::gemm_blocked,
+    maxflow::{dinic, FlowNetwork, NetworkError, ValidationPolicy},
+    montecarlo::{price_european_call, OptionParams, OptionPrice},
+    sort::{multiset_hash, SortAlgorithm},
+    MembershipProof, Sudoku,
+};
+
+/// Returns a [GuestFailure] from the enclosing entry point if a condition
+/// does not hold, the counterpart of [guest_assert](crate::guest_assert).
+macro_rules! ensure {
+    ($cond:expr, $code:expr, $context:expr) => {
+        if !$cond {
+            return Err(GuestFailure {
+                code: $code,
+                context: $context,
+            });
+        }
+    };
+}
+
+/// Commits the value returned by an entry point, or reports its failure
+/// through [fail](crate::failure::fail).
+#[cfg(target_os = "zkvm")]
+pub fn commit_or_fail<T: serde::Serialize>(result: Result<T, GuestFailure>) {
+    match result {
+        Ok(journal) => risc0_zkvm::guest::env::commit(&journal),
+        Err(failure) => crate::failure::fail(failure.code, failure.context),
+    }
+}
+
+pub type BTreeInput = (BTreeIndex, Vec<BTreeQuery>);
+
+/// Answers the queries against the index, committing their number and a
+/// digest of the results, or the reason the index was rejected. There is no
+/// check that can fail.
+pub fn btree((index, queries): BTreeInput) -> Result<(u32, Digest), IndexError> {
+    // Reject a malformed index with a committed reason instead of panicking
+    // or looping while traversing it.
+    index.validate()?;
+
+    // Encode every query result as a flat sequence of words before hashing.
+    let mut results: Vec<u64> = Vec::new();
+    for query in queries.iter() {
+        match query {
+            BTreeQuery::Get(key) => match index.get(*key) {
+                Some(value) => results.extend([1, value]),
+                None => results.push(0),
+            },
+            BTreeQuery::Range(start, end) => {
+                let entries = index.range(*start, *end);
+                results.push(entries.len() as u64);
+                results.extend(entries.iter().flat_map(|(k, v)| [*k, *v]));
+            }
+        }
+    }
+
+    let bytes: Vec<u8> = results.iter().flat_map(|x| x.to_le_bytes()).collect();
+    let digest = sha::Impl::hash_bytes(&bytes);
+    Ok((queries.len() as u32, *digest))
+}
+
+pub type GemmInput = (u32, u32, Vec<i32>, Vec<i32>);
+
+/// Multiplies the matrices, committing their dimension and a digest of the
+/// product.
+pub fn gemm((n, block, a, b): GemmInput) -> Result<(u32, Digest), GuestFailure> {
+    let c = gemm_blocked(n as usize, block as usize, &a, &b).ok_or(GuestFailure {
+        code: ErrorCode::InvalidDimensions,
+        context: 0,
+    })?;
+    let bytes: Vec<u8> = c.iter().flat_map(|x| x.to_le_bytes()).collect();
+    let digest = sha::Impl::hash_bytes(&bytes);
+    Ok((n, *digest))
+}
+
+pub type MaxflowInput = (ValidationPolicy, FlowNetwork);
+
+/// The policy, the digest of the network, and either the flow value with a
+/// digest of its cut or the reason the network was rejected.
+pub type MaxflowJournal = (
+    ValidationPolicy,
+    Digest,
+    Result<(u64, Digest), NetworkError>,
+);
+
+/// Solves the network and certifies the flow with a minimum cut.
+pub fn maxflow((policy, network): MaxflowInput) -> Result<MaxflowJournal, GuestFailure> {
+    // Commit the policy and the digest of the network with every result, so
+    // that a flow value or a rejection is bound to the network it was
+    // computed for and to the limits it was checked against.
+    let network_digest = network.digest();
+
+    // Reject malformed input with a committed reason instead of panicking, so
+    // the host can prove that a graph was invalid.
+    if let Err(err) = network.validate(&policy) {
+        return Ok((policy, network_digest, Err(err)));
+    }
+    checkpoint!("validated");
+
+    let flow = dinic(&network);
+    checkpoint!("solved");
+
+    // Check the min-cut certificate: it must separate the source from the sink
+    // and have a capacity equal to the flow value.
+    ensure!(
+        flow.source_side[network.source as usize],
+        ErrorCode::InvalidCut,
+        0
+    );
+    ensure!(
+        !flow.source_side[network.sink as usize],
+        ErrorCode::InvalidCut,
+        1
+    );
+    let cut_edges = network.cut_edges(&flow.source_side);
+    let cut_capacity: u64 = cut_edges.iter().map(|&i| network.edges[i as usize].2).sum();
+    ensure!(cut_capacity == flow.value, ErrorCode::InvalidCut, 2);
+    checkpoint!("certified");
+
+    let cut_bytes: Vec<u8> = cut_edges.iter().flat_map(|i| i.to_le_bytes()).collect();
+    let cut_digest = sha::Impl::hash_bytes(&cut_bytes);
+    Ok((policy, network_digest, Ok((flow.value, *cut_digest))))
+}
+
+/// Verifies the proof, committing its leaf and root.
+pub fn membership(proof: MembershipProof) -> Result<(Digest, Digest), GuestFailure> {
+    ensure!(proof.verify(), ErrorCode::InvalidProof, 0);
+    Ok((proof.leaf, proof.root))
+}
+
+/// Prices the option, committing the parameters with the price.
+pub fn montecarlo(params: OptionParams) -> Result<(OptionParams, OptionPrice), GuestFailure> {
+    let price = price_european_call(&params).ok_or(GuestFailure {
+        code: ErrorCode::NoPaths,
+        context: 0,
+    })?;
+    Ok((params, price))
+}
+
+pub type SortInput = (SortAlgorithm, Vec<u32>);
+
+fn to_bytes(values: &[u32]) -> Vec<u8> {
+    values.iter().flat_map(|x| x.to_le_bytes()).collect()
+}
+
+/// Sorts the values and checks the result, committing the algorithm, the
+/// length and a digest of the sorted values.
+pub fn sort((algorithm, input): SortInput) -> Result<(SortAlgorithm, u32, Digest), GuestFailure> {
+    checkpoint!("read");
+
+    let mut sorted = input.clone();
+    algorithm.sort(&mut sorted);
+    checkpoint!("sorted");
+    let unsorted = sorted.windows(2).position(|w| w[0] > w[1]);
+    ensure!(
+        unsorted.is_none(),
+        ErrorCode::NotSorted,
+        unsorted.unwrap_or_default() as u32
+    );
+
+    // Check that the output is a permutation of the input, with a challenge
+    // derived from both so that neither can be chosen to cause a collision.
+    let sorted_bytes = to_bytes(&sorted);
+    let transcript = [to_bytes(&input), sorted_bytes.clone()].concat();
+    let challenge_digest = sha::Impl::hash_bytes(&transcript);
+    let challenge = u64::from_le_bytes(challenge_digest.as_bytes()[..8].try_into().unwrap());
+    ensure!(
+        multiset_hash(&input, challenge) == multiset_hash(&sorted, challenge),
+        ErrorCode::NotPermutation,
+        0
+    );
+    checkpoint!("checked");
+
+    let digest = sha::Impl::hash_bytes(&sorted_bytes);
+    Ok((algorithm, sorted.len() as u32, *digest))
+}
+
+/// Checks the solution, committing a digest of the grid.
+pub fn sudoku(puzzle: Sudoku) -> Result<Digest, GuestFailure> {
+    ensure!(valid_solution(&puzzle), ErrorCode::InvalidSolution, 0);
+    Ok(*sha::Impl::hash_bytes(puzzle.0.as_flattened()))
+}
+
+fn valid_solution(sudoku: &Sudoku) -> bool {
+    for i in 0..9 {
+        // Sum as u32 so that large cells cannot wrap around to 45.
+        let mut line = 0u32;
+        let mut col = 0u32;
+        let mut sub = 0u32;
+
+        for j in 0..9 {
+            line += sudoku.0[i][j] as u32;
+            col += sudoku.0[j][i] as u32;
+            sub += sudoku.0[((i / 3) * 3) + j / 3][i % 3 * 3 + j % 3] as u32;
+        }
+
+        if line != 45 || col != 45 || sub != 45 {
+            return false;
+        }
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sudoku_sums_do_not_wrap() {
+        let solved = Sudoku(core::array::from_fn(|i| {
+            core::array::from_fn(|j| ((i * 3 + i / 3 + j) % 9 + 1) as u8)
+        }));
+        assert!(sudoku(solved.clone()).is_ok());
+
+        // Every row, column and box holds one 1 and one 2, so raising the 1s
+        // by 199 and lowering the 2s by 199 only changes the sums by 256.
+        let mut wrapped = solved;
+        for cell in wrapped.0.as_flattened_mut() {
+            *cell = match *cell {
+                1 => 200,
+                2 => 59,
+                x => x,
+            };
+        }
+        assert_eq!(
+            sudoku(wrapped).unwrap_err().code,
+            ErrorCode::InvalidSolution
+        );
+    }
+}
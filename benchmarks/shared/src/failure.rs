@@ -0,0 +1,118 @@
+// Copyright 2025 RISC Zero, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Machine-readable failure reporting for guests.
+//!
+//! Instead of panicking, a guest that detects a failed check commits a
+//! [GuestFailure] to the journal and halts with [FAILURE_EXIT_CODE]. The host
+//! can then decode the reason from the journal rather than parsing a panic
+//! message.
+
+use core::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// Exit code with which a guest halts after a failed [guest_assert](crate::guest_assert).
+pub const FAILURE_EXIT_CODE: u8 = 1;
+
+/// The check that failed in a guest.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorCode {
+    /// A membership proof does not lead to the expected root.
+    InvalidProof,
+    /// A minimum cut does not separate the terminals or does not match the flow.
+    InvalidCut,
+    /// A sorted output is out of order.
+    NotSorted,
+    /// A sorted output is not a permutation of its input.
+    NotPermutation,
+    /// A Sudoku solution breaks a row, column or box constraint.
+    InvalidSolution,
+    /// A public key could not be decoded.
+    InvalidKey,
+    /// A signature could not be decoded or does not verify. The context is
+    /// the iteration at which it did not verify, or [UNDECODED] if it could
+    /// not be decoded.
+    InvalidSignature,
+    /// A JSON document could not be parsed.
+    InvalidDocument,
+    /// A PNG image could not be decoded.
+    InvalidImage,
+    /// There were no chunks to build a Merkle tree from.
+    NoChunks,
+    /// The lengths of the matrices do not match their dimension, or the
+    /// block size is zero.
+    InvalidDimensions,
+    /// A pricing run has no paths.
+    NoPaths,
+    /// The receipt at the given index could not be verified.
+    InvalidReceipt,
+}
+
+/// The context of a failure to decode an input, as opposed to a check that
+/// failed at some index.
+pub const UNDECODED: u32 = u32::MAX;
+
+/// The record committed by a guest when a [guest_assert](crate::guest_assert) fails.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GuestFailure {
+    pub code: ErrorCode,
+    /// A check-specific value, such as an index, identifying where the check
+    /// failed.
+    pub context: u32,
+}
+
+impl fmt::Display for GuestFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?} (context {})", self.code, self.context)
+    }
+}
+
+/// Commits a [GuestFailure] to the journal and halts with
+/// [FAILURE_EXIT_CODE].
+///
+/// Anything committed before the failure stays in the journal, so guests
+/// should check their inputs and results before committing them.
+#[cfg(target_os = "zkvm")]
+pub fn fail(code: ErrorCode, context: u32) -> ! {
+    use risc0_zkvm::guest::env;
+
+    env::commit(&GuestFailure { code, context });
+    env::exit(FAILURE_EXIT_CODE)
+}
+
+/// Checks a condition in a guest, reporting a failure through [fail] instead
+/// of panicking if it does not hold.
+#[macro_export]
+macro_rules! guest_assert {
+    ($cond:expr, $code:expr, $context:expr) => {
+        if !$cond {
+            $crate::failure::fail($code, $context)
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display() {
+        let failure = GuestFailure {
+            code: ErrorCode::NotSorted,
+            context: 7,
+        };
+        assert_eq!(failure.to_string(), "NotSorted (context 7)");
+    }
+}
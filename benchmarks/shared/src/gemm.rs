@@ -0,0 +1,76 @@
+// Copyright 2025 RISC Zero, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// Multiplies two row-major `n x n` matrices, iterating over `block x block`
+/// tiles so that the working set of each tile stays small.
+///
+/// Arithmetic wraps on overflow so that the result is well defined for any
+/// input. Returns `None` if either matrix does not have `n * n` elements or if
+/// `block` is zero.
+pub fn gemm_blocked(n: usize, block: usize, a: &[i32], b: &[i32]) -> Option<Vec<i32>> {
+    if n.checked_mul(n) != Some(a.len()) || b.len() != a.len() || block == 0 {
+        return None;
+    }
+
+    let mut c = vec![0i32; n * n];
+    for ii in (0..n).step_by(block) {
+        for kk in (0..n).step_by(block) {
+            for jj in (0..n).step_by(block) {
+                for i in ii..(ii + block).min(n) {
+                    for k in kk..(kk + block).min(n) {
+                        let aik = a[i * n + k];
+                        for j in jj..(jj + block).min(n) {
+                            let prod = aik.wrapping_mul(b[k * n + j]);
+                            c[i * n + j] = c[i * n + j].wrapping_add(prod);
+                        }
+                    }
+                }
+            }
+        }
+    }
+    Some(c)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn gemm_naive(n: usize, a: &[i32], b: &[i32]) -> Vec<i32> {
+        let mut c = vec![0i32; n * n];
+        for i in 0..n {
+            for j in 0..n {
+                for k in 0..n {
+                    c[i * n + j] =
+                        c[i * n + j].wrapping_add(a[i * n + k].wrapping_mul(b[k * n + j]));
+                }
+            }
+        }
+        c
+    }
+
+    #[test]
+    fn blocked_matches_naive() {
+        let n = 13;
+        let a: Vec<i32> = (0..n * n).map(|x| (x as i32 * 7919) ^ 0x5a5a).collect();
+        let b: Vec<i32> = (0..n * n).map(|x| (x as i32 * 104729) - 1000).collect();
+        let expected = gemm_naive(n, &a, &b);
+        for block in [1, 4, 5, 13, 16] {
+            assert_eq!(gemm_blocked(n, block, &a, &b), Some(expected.clone()));
+        }
+        assert_eq!(gemm_blocked(n, 0, &a, &b), None);
+        assert_eq!(gemm_blocked(n + 1, 4, &a, &b), None);
+        assert_eq!(gemm_blocked(n, 4, &a, &b[1..]), None);
+        assert_eq!(gemm_blocked(usize::MAX, 4, &a, &b), None);
+    }
+}
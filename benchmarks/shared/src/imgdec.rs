@@ -0,0 +1,79 @@
+// Copyright 2025 RISC Zero, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// Converts 8-bit RGB pixels to luma using integer BT.601 weights.
+pub fn rgb_to_luma(rgb: &[u8]) -> Vec<u8> {
+    rgb.chunks_exact(3)
+        .map(|px| ((px[0] as u32 * 77 + px[1] as u32 * 150 + px[2] as u32 * 29) >> 8) as u8)
+        .collect()
+}
+
+/// Computes the 64-bit difference hash (dHash) of a luma image.
+///
+/// The image is box-filtered down to 9x8 cells, and each bit records whether
+/// a cell is darker than its right neighbour. Visually similar images have
+/// hashes with a small Hamming distance.
+pub fn dhash(width: usize, height: usize, luma: &[u8]) -> u64 {
+    assert_eq!(luma.len(), width * height);
+    assert!(width > 0 && height > 0);
+
+    const COLS: usize = 9;
+    const ROWS: usize = 8;
+    let span = |i: usize, n: usize, len: usize| {
+        let start = i * len / n;
+        start..((i + 1) * len / n).max(start + 1).min(len)
+    };
+
+    let mut cells = [[0u32; COLS]; ROWS];
+    for (cy, row) in cells.iter_mut().enumerate() {
+        let ys = span(cy, ROWS, height);
+        for (cx, cell) in row.iter_mut().enumerate() {
+            let xs = span(cx, COLS, width);
+            let mut sum = 0;
+            for y in ys.clone() {
+                sum += luma[y * width + xs.start..y * width + xs.end]
+                    .iter()
+                    .map(|&x| x as u32)
+                    .sum::<u32>();
+            }
+            *cell = sum / (ys.len() * xs.len()) as u32;
+        }
+    }
+
+    let mut hash = 0u64;
+    for row in cells.iter() {
+        for cx in 0..COLS - 1 {
+            hash = (hash << 1) | (row[cx] < row[cx + 1]) as u64;
+        }
+    }
+    hash
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dhash_of_gradients() {
+        let (width, height) = (45, 40);
+        let increasing: Vec<u8> = (0..width * height).map(|i| (i % width * 5) as u8).collect();
+        assert_eq!(dhash(width, height, &increasing), u64::MAX);
+
+        let decreasing: Vec<u8> = increasing.iter().map(|x| 255 - x).collect();
+        assert_eq!(dhash(width, height, &decreasing), 0);
+
+        // Images smaller than the hash grid are still accepted.
+        assert_eq!(dhash(1, 1, &[7]), 0);
+    }
+}
@@ -12,6 +12,18 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod btree;
+pub mod checkpoint;
+pub mod entry;
+pub mod failure;
+pub mod gemm;
+pub mod imgdec;
+pub mod maxflow;
+pub mod merkle;
+pub mod montecarlo;
+pub mod rng;
+pub mod sort;
+
 use risc0_zkvm::sha::{self, Digest, Sha256};
 use serde::{Deserialize, Serialize};
 
@@ -0,0 +1,528 @@
+// Copyright 2025 RISC Zero, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeSet;
+
+use risc0_zkvm::sha::{self, Digest, Sha256};
+use serde::{Deserialize, Serialize};
+
+/// A directed network with integer edge capacities.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FlowNetwork {
+    pub num_nodes: u32,
+    pub source: u32,
+    pub sink: u32,
+    /// Edges as `(from, to, capacity)`.
+    pub edges: Vec<(u32, u32, u64)>,
+}
+
+/// The largest number of nodes that any [ValidationPolicy] allows, so that a
+/// policy read from untrusted input cannot let the solver exhaust memory.
+pub const MAX_NODES: u32 = 1 << 20;
+
+/// Limits that a [FlowNetwork] must satisfy before it is solved.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ValidationPolicy {
+    /// The largest allowed number of nodes, which bounds the memory used by
+    /// the solver independently of the number of edges. Values above
+    /// [MAX_NODES] are treated as [MAX_NODES].
+    pub max_nodes: u32,
+    /// The largest allowed edge capacity.
+    pub max_capacity: u64,
+    /// Whether more than one edge may connect the same ordered pair of nodes.
+    pub allow_parallel_edges: bool,
+}
+
+impl Default for ValidationPolicy {
+    fn default() -> Self {
+        Self {
+            max_nodes: MAX_NODES,
+            max_capacity: u32::MAX as u64,
+            allow_parallel_edges: true,
+        }
+    }
+}
+
+/// The reason a [FlowNetwork] was rejected by [FlowNetwork::validate].
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NetworkError {
+    /// The network has more nodes than the policy allows.
+    TooManyNodes,
+    /// The source or sink is not a node of the network.
+    TerminalOutOfRange,
+    /// The source and sink are the same node.
+    SourceIsSink,
+    /// The edge at the given index refers to a node that does not exist.
+    NodeOutOfRange { edge: u32 },
+    /// The edge at the given index exceeds the maximum capacity.
+    CapacityTooLarge { edge: u32 },
+    /// The total capacity of the edges up to the given index does not fit in
+    /// a `u64`.
+    CapacityOverflow { edge: u32 },
+    /// The edge at the given index duplicates an earlier edge.
+    ParallelEdge { edge: u32 },
+}
+
+/// The value of a maximum flow, along with a minimum cut certifying it.
+#[derive(Debug)]
+pub struct MaxFlow {
+    pub value: u64,
+    /// Whether each node is on the source side of the cut.
+    pub source_side: Vec<bool>,
+}
+
+impl FlowNetwork {
+    /// Checks that the network is well formed and within the given policy,
+    /// reporting the first violation found.
+    ///
+    /// The total capacity of a valid network fits in a `u64`, and so do every
+    /// flow and cut of it, whatever the policy's maximum capacity.
+    pub fn validate(&self, policy: &ValidationPolicy) -> Result<(), NetworkError> {
+        if self.num_nodes > policy.max_nodes.min(MAX_NODES) {
+            return Err(NetworkError::TooManyNodes);
+        }
+        if self.source >= self.num_nodes || self.sink >= self.num_nodes {
+            return Err(NetworkError::TerminalOutOfRange);
+        }
+        if self.source == self.sink {
+            return Err(NetworkError::SourceIsSink);
+        }
+
+        let mut seen = BTreeSet::new();
+        let mut total_capacity = 0u64;
+        for (i, &(from, to, capacity)) in self.edges.iter().enumerate() {
+            let edge = i as u32;
+            if from >= self.num_nodes || to >= self.num_nodes {
+                return Err(NetworkError::NodeOutOfRange { edge });
+            }
+            if capacity > policy.max_capacity {
+                return Err(NetworkError::CapacityTooLarge { edge });
+            }
+            total_capacity = total_capacity
+                .checked_add(capacity)
+                .ok_or(NetworkError::CapacityOverflow { edge })?;
+            if !policy.allow_parallel_edges && !seen.insert((from, to)) {
+                return Err(NetworkError::ParallelEdge { edge });
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns a digest of the network, hashing the node count, the source
+    /// and the sink as little-endian `u32`s, followed by each edge as its two
+    /// nodes and a little-endian `u64` capacity.
+    pub fn digest(&self) -> Digest {
+        let mut bytes = Vec::with_capacity(12 + 16 * self.edges.len());
+        for x in [self.num_nodes, self.source, self.sink] {
+            bytes.extend_from_slice(&x.to_le_bytes());
+        }
+        for &(from, to, capacity) in &self.edges {
+            bytes.extend_from_slice(&from.to_le_bytes());
+            bytes.extend_from_slice(&to.to_le_bytes());
+            bytes.extend_from_slice(&capacity.to_le_bytes());
+        }
+        *sha::Impl::hash_bytes(&bytes)
+    }
+
+    /// Returns the indices of the edges crossing from the source side to the
+    /// sink side of the given cut.
+    pub fn cut_edges(&self, source_side: &[bool]) -> Vec<u32> {
+        (0..self.edges.len() as u32)
+            .filter(|&i| {
+                let (from, to, _) = self.edges[i as usize];
+                source_side[from as usize] && !source_side[to as usize]
+            })
+            .collect()
+    }
+
+    /// Returns the total capacity of the edges crossing the given cut.
+    pub fn cut_capacity(&self, source_side: &[bool]) -> u64 {
+        self.cut_edges(source_side)
+            .iter()
+            .map(|&i| self.edges[i as usize].2)
+            .sum()
+    }
+}
+
+/// Residual graph used by Dinic's algorithm, where arc `i ^ 1` is the
+/// reverse of arc `i`.
+struct Residual {
+    head: Vec<u32>,
+    cap: Vec<u64>,
+    adj: Vec<Vec<u32>>,
+    level: Vec<u32>,
+    next_arc: Vec<usize>,
+}
+
+impl Residual {
+    fn bfs(&mut self, source: u32, sink: u32) -> bool {
+        self.level.fill(u32::MAX);
+        self.level[source as usize] = 0;
+        let mut queue = std::collections::VecDeque::from([source]);
+        while let Some(node) = queue.pop_front() {
+            for &arc in self.adj[node as usize].iter() {
+                let to = self.head[arc as usize];
+                if self.cap[arc as usize] > 0 && self.level[to as usize] == u32::MAX {
+                    self.level[to as usize] = self.level[node as usize] + 1;
+                    queue.push_back(to);
+                }
+            }
+        }
+        self.level[sink as usize] != u32::MAX
+    }
+
+    /// Finds an augmenting path in the level graph and pushes as much flow
+    /// along it as it allows, returning the amount pushed.
+    ///
+    /// The search keeps the current path on an explicit stack rather than
+    /// recursing, since a path can visit every node and the guest stack is
+    /// far smaller than the networks it accepts.
+    fn augment(&mut self, source: u32, sink: u32) -> u64 {
+        // The arc taken out of each node on the current path.
+        let mut path: Vec<usize> = Vec::new();
+        let mut node = source;
+        loop {
+            if node == sink {
+                let pushed = path
+                    .iter()
+                    .map(|&arc| self.cap[arc])
+                    .min()
+                    .unwrap_or(u64::MAX);
+                for &arc in path.iter() {
+                    self.cap[arc] -= pushed;
+                    self.cap[arc ^ 1] += pushed;
+                }
+                return pushed;
+            }
+
+            let mut advanced = false;
+            while self.next_arc[node as usize] < self.adj[node as usize].len() {
+                let arc = self.adj[node as usize][self.next_arc[node as usize]] as usize;
+                let to = self.head[arc];
+                if self.cap[arc] > 0 && self.level[to as usize] == self.level[node as usize] + 1 {
+                    path.push(arc);
+                    node = to;
+                    advanced = true;
+                    break;
+                }
+                self.next_arc[node as usize] += 1;
+            }
+
+            if !advanced {
+                // A dead end: retreat along the path and skip the arc that
+                // led here.
+                let Some(arc) = path.pop() else {
+                    return 0;
+                };
+                node = self.head[arc ^ 1];
+                self.next_arc[node as usize] += 1;
+            }
+        }
+    }
+}
+
+/// Computes a maximum flow with Dinic's algorithm.
+///
+/// The returned cut is the set of nodes reachable from the source in the final
+/// residual graph, whose capacity equals the flow value by the max-flow
+/// min-cut theorem.
+pub fn dinic(network: &FlowNetwork) -> MaxFlow {
+    let num_nodes = network.num_nodes as usize;
+    let mut residual = Residual {
+        head: Vec::with_capacity(network.edges.len() * 2),
+        cap: Vec::with_capacity(network.edges.len() * 2),
+        adj: vec![Vec::new(); num_nodes],
+        level: vec![u32::MAX; num_nodes],
+        next_arc: vec![0; num_nodes],
+    };
+    for &(from, to, capacity) in network.edges.iter() {
+        residual.adj[from as usize].push(residual.head.len() as u32);
+        residual.head.push(to);
+        residual.cap.push(capacity);
+        residual.adj[to as usize].push(residual.head.len() as u32);
+        residual.head.push(from);
+        residual.cap.push(0);
+    }
+
+    let mut value = 0;
+    while residual.bfs(network.source, network.sink) {
+        residual.next_arc.fill(0);
+        loop {
+            let pushed = residual.augment(network.source, network.sink);
+            if pushed == 0 {
+                break;
+            }
+            value += pushed;
+        }
+    }
+
+    // The last BFS left every node reachable from the source labelled.
+    let source_side = residual.level.iter().map(|&l| l != u32::MAX).collect();
+    MaxFlow { value, source_side }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::Rng;
+
+    use crate::rng::{ChaCha12Rng, SeedableRng};
+
+    #[test]
+    fn textbook_network() {
+        // The example network from CLRS, with a maximum flow of 23.
+        let network = FlowNetwork {
+            num_nodes: 6,
+            source: 0,
+            sink: 5,
+            edges: vec![
+                (0, 1, 16),
+                (0, 2, 13),
+                (2, 1, 4),
+                (1, 3, 12),
+                (3, 2, 9),
+                (2, 4, 14),
+                (4, 3, 7),
+                (3, 5, 20),
+                (4, 5, 4),
+            ],
+        };
+        let flow = dinic(&network);
+        assert_eq!(flow.value, 23);
+        assert_eq!(network.cut_capacity(&flow.source_side), 23);
+        assert_eq!(network.cut_edges(&flow.source_side), vec![3, 6, 8]);
+    }
+
+    #[test]
+    fn digest_binds_the_whole_network() {
+        let network = FlowNetwork {
+            num_nodes: 3,
+            source: 0,
+            sink: 2,
+            edges: vec![(0, 1, 5), (1, 2, 3)],
+        };
+        let variants = [
+            FlowNetwork {
+                num_nodes: 4,
+                ..network.clone()
+            },
+            FlowNetwork {
+                source: 1,
+                ..network.clone()
+            },
+            FlowNetwork {
+                sink: 1,
+                ..network.clone()
+            },
+            FlowNetwork {
+                edges: vec![(0, 1, 5), (1, 2, 4)],
+                ..network.clone()
+            },
+            FlowNetwork {
+                edges: vec![(0, 1, 5)],
+                ..network.clone()
+            },
+        ];
+        assert_eq!(network.digest(), network.clone().digest());
+        for variant in variants {
+            assert_ne!(variant.digest(), network.digest());
+        }
+    }
+
+    #[test]
+    fn validation() {
+        let network = |source, sink, edges| FlowNetwork {
+            num_nodes: 3,
+            source,
+            sink,
+            edges,
+        };
+        let policy = ValidationPolicy::default();
+        let strict = ValidationPolicy {
+            max_nodes: 3,
+            max_capacity: 10,
+            allow_parallel_edges: false,
+        };
+
+        let valid = network(0, 2, vec![(0, 1, 5), (1, 2, 10), (0, 1, 1)]);
+        assert_eq!(valid.validate(&policy), Ok(()));
+        assert_eq!(
+            valid.validate(&strict),
+            Err(NetworkError::ParallelEdge { edge: 2 })
+        );
+        assert_eq!(
+            network(0, 3, vec![]).validate(&policy),
+            Err(NetworkError::TerminalOutOfRange)
+        );
+        assert_eq!(
+            FlowNetwork {
+                num_nodes: 4,
+                ..valid.clone()
+            }
+            .validate(&strict),
+            Err(NetworkError::TooManyNodes)
+        );
+        assert_eq!(
+            FlowNetwork {
+                num_nodes: MAX_NODES + 1,
+                ..valid.clone()
+            }
+            .validate(&ValidationPolicy {
+                max_nodes: u32::MAX,
+                ..ValidationPolicy::default()
+            }),
+            Err(NetworkError::TooManyNodes)
+        );
+        assert_eq!(
+            network(1, 1, vec![]).validate(&policy),
+            Err(NetworkError::SourceIsSink)
+        );
+        assert_eq!(
+            network(0, 2, vec![(0, 1, 1), (1, 3, 1)]).validate(&policy),
+            Err(NetworkError::NodeOutOfRange { edge: 1 })
+        );
+        assert_eq!(
+            network(0, 2, vec![(0, 1, 11)]).validate(&strict),
+            Err(NetworkError::CapacityTooLarge { edge: 0 })
+        );
+
+        let unbounded = ValidationPolicy {
+            max_capacity: u64::MAX,
+            ..ValidationPolicy::default()
+        };
+        let saturated = network(0, 2, vec![(0, 1, u64::MAX), (1, 2, u64::MAX)]);
+        assert_eq!(
+            saturated.validate(&unbounded),
+            Err(NetworkError::CapacityOverflow { edge: 1 })
+        );
+        let large = network(0, 2, vec![(0, 1, u64::MAX - 1), (1, 2, 1)]);
+        assert_eq!(large.validate(&unbounded), Ok(()));
+        assert_eq!(dinic(&large).value, 1);
+    }
+
+    #[test]
+    fn arbitrary_networks_are_rejected_or_solved() {
+        // A cheap fuzzer: networks built from a mix of small and extreme
+        // values must either be rejected by validation or be solved with a
+        // certifying cut, and must never panic or exhaust memory.
+        let mut rng = ChaCha12Rng::seed_from_u64(7);
+        let mut value = |max: u64| match rng.random_range(0..4) {
+            0 => max,
+            1 => rng.random_range(0..4),
+            _ => rng.random_range(0..=max.min(12)),
+        };
+
+        let policy = ValidationPolicy::default();
+        let (mut rejected, mut solved) = (0, 0);
+        for _ in 0..2000 {
+            let num_edges = value(256) as usize;
+            let network = FlowNetwork {
+                num_nodes: value(u32::MAX as u64) as u32,
+                source: value(u32::MAX as u64) as u32,
+                sink: value(u32::MAX as u64) as u32,
+                edges: (0..num_edges)
+                    .map(|_| {
+                        (
+                            value(u32::MAX as u64) as u32,
+                            value(u32::MAX as u64) as u32,
+                            value(u64::MAX),
+                        )
+                    })
+                    .collect(),
+            };
+            if network.validate(&policy).is_err() {
+                rejected += 1;
+                continue;
+            }
+            let flow = dinic(&network);
+            assert_eq!(network.cut_capacity(&flow.source_side), flow.value);
+            solved += 1;
+        }
+        assert!(rejected > 0 && solved > 0);
+    }
+
+    #[test]
+    fn arbitrary_long_paths_are_solved() {
+        // Chains through randomly labelled nodes, with a few shortcuts, make
+        // the augmenting paths nearly as long as the network.
+        let mut rng = ChaCha12Rng::seed_from_u64(11);
+        for _ in 0..20 {
+            let num_nodes: u32 = rng.random_range(2..20_002);
+            let mut order: Vec<u32> = (0..num_nodes).collect();
+            for i in (1..order.len()).rev() {
+                order.swap(i, rng.random_range(0..=i));
+            }
+            let mut edges: Vec<_> = order
+                .windows(2)
+                .map(|w| (w[0], w[1], rng.random_range(1..=u32::MAX as u64)))
+                .collect();
+            for _ in 0..rng.random_range(0..8) {
+                let from = rng.random_range(0..num_nodes);
+                let to = rng.random_range(0..num_nodes);
+                edges.push((from, to, rng.random_range(0..u32::MAX as u64)));
+            }
+            let network = FlowNetwork {
+                num_nodes,
+                source: order[0],
+                sink: order[num_nodes as usize - 1],
+                edges,
+            };
+            assert_eq!(network.validate(&ValidationPolicy::default()), Ok(()));
+            let flow = dinic(&network);
+            assert!(flow.value > 0);
+            assert_eq!(network.cut_capacity(&flow.source_side), flow.value);
+        }
+    }
+
+    #[test]
+    fn deep_path() {
+        // A single path through every node, which makes the augmenting path
+        // as long as the network. This overflows a recursive search.
+        let num_nodes = 50_000;
+        let network = FlowNetwork {
+            num_nodes,
+            source: 0,
+            sink: num_nodes - 1,
+            edges: (0..num_nodes - 1)
+                .map(|i| (i, i + 1, 3 + i as u64 % 5))
+                .collect(),
+        };
+        assert_eq!(network.validate(&ValidationPolicy::default()), Ok(()));
+        let flow = dinic(&network);
+        assert_eq!(flow.value, 3);
+        assert_eq!(network.cut_capacity(&flow.source_side), 3);
+    }
+
+    #[test]
+    fn cut_certifies_flow() {
+        let mut rng = ChaCha12Rng::seed_from_u64(0x2545_f491);
+        let mut next = |bound: u32| rng.random_range(0..bound);
+
+        for num_nodes in [2, 5, 20, 100] {
+            let edges = (0..num_nodes * 4)
+                .map(|_| (next(num_nodes), next(num_nodes), next(50) as u64))
+                .collect();
+            let network = FlowNetwork {
+                num_nodes,
+                source: 0,
+                sink: num_nodes - 1,
+                edges,
+            };
+            let flow = dinic(&network);
+            assert!(flow.source_side[0]);
+            assert!(!flow.source_side[num_nodes as usize - 1]);
+            assert_eq!(network.cut_capacity(&flow.source_side), flow.value);
+        }
+    }
+}
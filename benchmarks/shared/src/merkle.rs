@@ -0,0 +1,187 @@
+// Copyright 2025 RISC Zero, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// A hash function mapping arbitrary bytes to a 32-byte digest.
+pub type HashFn = fn(&[u8]) -> [u8; 32];
+
+/// Builds a binary Merkle tree root incrementally from a stream of leaves,
+/// keeping only one pending node per tree level.
+///
+/// Leaves are hashed, and each parent is the hash of its children's
+/// concatenated digests. A node without a sibling is promoted unchanged.
+pub struct MerkleBuilder {
+    hash: HashFn,
+    stack: Vec<(u32, [u8; 32])>,
+}
+
+impl MerkleBuilder {
+    pub fn new(hash: HashFn) -> Self {
+        Self {
+            hash,
+            stack: Vec::new(),
+        }
+    }
+
+    pub fn push_leaf(&mut self, data: &[u8]) {
+        let mut node = (0, (self.hash)(data));
+        while let Some(&(level, left)) = self.stack.last() {
+            if level != node.0 {
+                break;
+            }
+            self.stack.pop();
+            node = (level + 1, self.hash_pair(&left, &node.1));
+        }
+        self.stack.push(node);
+    }
+
+    /// Returns the root, or `None` if no leaves were pushed.
+    pub fn finalize(mut self) -> Option<[u8; 32]> {
+        let mut root = self.stack.pop()?.1;
+        while let Some((_, left)) = self.stack.pop() {
+            root = self.hash_pair(&left, &root);
+        }
+        Some(root)
+    }
+
+    fn hash_pair(&self, left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+        let mut buf = [0u8; 64];
+        buf[..32].copy_from_slice(left);
+        buf[32..].copy_from_slice(right);
+        (self.hash)(&buf)
+    }
+}
+
+const K: [u32; 64] = [
+    0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5,
+    0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174,
+    0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da,
+    0x983e5152, 0xa831c66d, 0xb00327c8, 0xbf597fc7, 0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967,
+    0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13, 0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85,
+    0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 0xd192e819, 0xd6990624, 0xf40e3585, 0x106aa070,
+    0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a, 0x5b9cca4f, 0x682e6ff3,
+    0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7, 0xc67178f2,
+];
+
+/// A plain software SHA-256, used as the baseline against the accelerated
+/// implementation.
+pub fn software_sha256(data: &[u8]) -> [u8; 32] {
+    let mut state: [u32; 8] = [
+        0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab,
+        0x5be0cd19,
+    ];
+
+    let bit_len = (data.len() as u64).wrapping_mul(8);
+    let mut tail = data[data.len() / 64 * 64..].to_vec();
+    tail.push(0x80);
+    while tail.len() % 64 != 56 {
+        tail.push(0);
+    }
+    tail.extend_from_slice(&bit_len.to_be_bytes());
+
+    for block in data.chunks_exact(64).chain(tail.chunks_exact(64)) {
+        let mut w = [0u32; 64];
+        for (i, word) in block.chunks_exact(4).enumerate() {
+            w[i] = u32::from_be_bytes(word.try_into().unwrap());
+        }
+        for i in 16..64 {
+            let s0 = w[i - 15].rotate_right(7) ^ w[i - 15].rotate_right(18) ^ (w[i - 15] >> 3);
+            let s1 = w[i - 2].rotate_right(17) ^ w[i - 2].rotate_right(19) ^ (w[i - 2] >> 10);
+            w[i] = w[i - 16]
+                .wrapping_add(s0)
+                .wrapping_add(w[i - 7])
+                .wrapping_add(s1);
+        }
+
+        let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = state;
+        for i in 0..64 {
+            let s1 = e.rotate_right(6) ^ e.rotate_right(11) ^ e.rotate_right(25);
+            let ch = (e & f) ^ (!e & g);
+            let t1 = h
+                .wrapping_add(s1)
+                .wrapping_add(ch)
+                .wrapping_add(K[i])
+                .wrapping_add(w[i]);
+            let s0 = a.rotate_right(2) ^ a.rotate_right(13) ^ a.rotate_right(22);
+            let maj = (a & b) ^ (a & c) ^ (b & c);
+            let t2 = s0.wrapping_add(maj);
+            h = g;
+            g = f;
+            f = e;
+            e = d.wrapping_add(t1);
+            d = c;
+            c = b;
+            b = a;
+            a = t1.wrapping_add(t2);
+        }
+        for (s, x) in state.iter_mut().zip([a, b, c, d, e, f, g, h]) {
+            *s = s.wrapping_add(x);
+        }
+    }
+
+    let mut out = [0u8; 32];
+    for (chunk, s) in out.chunks_exact_mut(4).zip(state) {
+        chunk.copy_from_slice(&s.to_be_bytes());
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use risc0_zkvm::sha::{self, Sha256};
+
+    use super::*;
+
+    fn accelerated_sha256(data: &[u8]) -> [u8; 32] {
+        sha::Impl::hash_bytes(data).as_bytes().try_into().unwrap()
+    }
+
+    #[test]
+    fn software_sha256_matches() {
+        for len in [0, 1, 3, 55, 56, 63, 64, 65, 119, 120, 1000] {
+            let data: Vec<u8> = (0..len).map(|i| (i * 31) as u8).collect();
+            assert_eq!(
+                software_sha256(&data),
+                accelerated_sha256(&data),
+                "len {len}"
+            );
+        }
+    }
+
+    #[test]
+    fn streaming_root_matches_levels() {
+        for num_leaves in 1..20u8 {
+            let leaves: Vec<[u8; 32]> = (0..num_leaves).map(|i| software_sha256(&[i])).collect();
+
+            // Reduce level by level, promoting nodes without a sibling.
+            let mut level = leaves;
+            while level.len() > 1 {
+                level = level
+                    .chunks(2)
+                    .map(|pair| match pair {
+                        [left, right] => software_sha256(&[*left, *right].concat()),
+                        [single] => *single,
+                        _ => unreachable!(),
+                    })
+                    .collect();
+            }
+
+            let mut builder = MerkleBuilder::new(software_sha256);
+            for i in 0..num_leaves {
+                builder.push_leaf(&[i]);
+            }
+            assert_eq!(builder.finalize(), Some(level[0]));
+        }
+        assert_eq!(MerkleBuilder::new(software_sha256).finalize(), None);
+    }
+}
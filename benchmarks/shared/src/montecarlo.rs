@@ -0,0 +1,183 @@
+// Copyright 2025 RISC Zero, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Monte Carlo pricing of a European call option using only fixed-point
+//! integer arithmetic, so that results are identical on every platform.
+
+use serde::{Deserialize, Serialize};
+
+use crate::rng::{ChaCha12Rng, RngBackend, SeedableRng, SuiteRng, Xoshiro256PlusPlus};
+
+/// Number of fractional bits in the Q32.32 fixed-point representation.
+pub const FRAC_BITS: u32 = 32;
+
+const ONE: i64 = 1 << FRAC_BITS;
+
+pub fn to_fixed(x: f64) -> i64 {
+    (x * ONE as f64).round() as i64
+}
+
+pub fn from_fixed(x: i64) -> f64 {
+    x as f64 / ONE as f64
+}
+
+fn mul(a: i64, b: i64) -> i64 {
+    ((a as i128 * b as i128) >> FRAC_BITS) as i64
+}
+
+/// Returns an approximately standard normal sample in fixed point, using the
+/// sum of twelve uniform samples (Irwin-Hall).
+fn next_normal(rng: &mut impl SuiteRng) -> i64 {
+    let sum: i64 = (0..12).map(|_| (rng.next_u64() >> 32) as i64).sum();
+    sum - 6 * ONE
+}
+
+/// Parameters of a pricing run. All rates are per time step and all values
+/// are in Q32.32 fixed point.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct OptionParams {
+    /// The generator of the samples, which is committed with the parameters.
+    pub rng: RngBackend,
+    pub seed: u64,
+    pub paths: u32,
+    pub steps: u32,
+    pub spot: i64,
+    pub strike: i64,
+    pub drift: i64,
+    pub volatility: i64,
+    /// Discount factor applied to the terminal payoff.
+    pub discount: i64,
+}
+
+/// A price estimate with its 95% confidence interval, in Q32.32 fixed point.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct OptionPrice {
+    pub price: i64,
+    pub ci_low: i64,
+    pub ci_high: i64,
+}
+
+/// Prices the option described by `params`, or returns `None` if there are no
+/// paths to simulate.
+///
+/// Arithmetic wraps on overflow so that the result is well defined for any
+/// parameters, however meaningless.
+pub fn price_european_call(params: &OptionParams) -> Option<OptionPrice> {
+    if params.paths == 0 {
+        return None;
+    }
+    let price = match params.rng {
+        RngBackend::ChaCha12 => simulate(params, ChaCha12Rng::seed_from_u64(params.seed)),
+        RngBackend::Xoshiro256PlusPlus => {
+            simulate(params, Xoshiro256PlusPlus::seed_from_u64(params.seed))
+        }
+    };
+    Some(price)
+}
+
+fn simulate(params: &OptionParams, mut rng: impl SuiteRng) -> OptionPrice {
+    let mut sum: i128 = 0;
+    let mut sum_sq: i128 = 0;
+    for _ in 0..params.paths {
+        let mut price = params.spot;
+        for _ in 0..params.steps {
+            let ret = params
+                .drift
+                .wrapping_add(mul(params.volatility, next_normal(&mut rng)));
+            price = price.wrapping_add(mul(price, ret));
+        }
+        let payoff = mul(price.wrapping_sub(params.strike).max(0), params.discount) as i128;
+        sum += payoff;
+        sum_sq += (payoff * payoff) >> FRAC_BITS;
+    }
+
+    let n = params.paths as i128;
+    let mean = sum / n;
+    let variance = (sum_sq / n - ((mean * mean) >> FRAC_BITS)).max(0);
+    let std_dev = ((variance as u128) << FRAC_BITS).isqrt() as i64;
+    let half_width = mul(to_fixed(1.96), std_dev) / (params.paths as u64).isqrt() as i64;
+
+    OptionPrice {
+        price: mean as i64,
+        ci_low: (mean as i64).wrapping_sub(half_width),
+        ci_high: (mean as i64).wrapping_add(half_width),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn params(paths: u32, volatility: f64) -> OptionParams {
+        // A one year option with 50 steps, 5% rate and the given volatility.
+        let steps = 50;
+        let dt = 1.0 / steps as f64;
+        OptionParams {
+            rng: RngBackend::ChaCha12,
+            seed: 42,
+            paths,
+            steps,
+            spot: to_fixed(100.0),
+            strike: to_fixed(100.0),
+            drift: to_fixed(0.05 * dt),
+            volatility: to_fixed(volatility * dt.sqrt()),
+            discount: to_fixed((-0.05f64).exp()),
+        }
+    }
+
+    #[test]
+    fn zero_volatility_is_exact() {
+        let result = price_european_call(&params(10, 0.0)).unwrap();
+        let expected = (100.0 * (1.0 + 0.05 / 50.0f64).powi(50) - 100.0) * (-0.05f64).exp();
+        assert!((from_fixed(result.price) - expected).abs() < 1e-6);
+        assert_eq!(result.ci_low, result.price);
+        assert_eq!(result.ci_high, result.price);
+    }
+
+    #[test]
+    fn no_paths() {
+        assert_eq!(price_european_call(&params(0, 0.2)), None);
+    }
+
+    #[test]
+    fn extreme_params_wrap() {
+        let params = OptionParams {
+            spot: i64::MAX,
+            strike: i64::MIN,
+            drift: i64::MAX,
+            volatility: i64::MIN,
+            discount: i64::MAX,
+            ..params(3, 0.0)
+        };
+        assert_eq!(
+            price_european_call(&params),
+            price_european_call(&params.clone())
+        );
+    }
+
+    #[test]
+    fn close_to_black_scholes() {
+        // The Black-Scholes price for these parameters is about 10.45.
+        for rng in RngBackend::ALL {
+            let params = OptionParams {
+                rng,
+                ..params(20000, 0.2)
+            };
+            let result = price_european_call(&params).unwrap();
+            assert!(result.ci_low < result.price && result.price < result.ci_high);
+            assert!((from_fixed(result.price) - 10.45).abs() < 0.5);
+            assert_eq!(Some(result), price_european_call(&params));
+        }
+    }
+}
@@ -0,0 +1,115 @@
+// Copyright 2025 RISC Zero, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The seedable generators behind every synthetic input, shared by the host,
+//! which generates the inputs of the jobs, and by guests that draw their own
+//! samples, such as the Monte Carlo workload.
+
+use core::{fmt, str::FromStr};
+
+pub use rand_chacha::ChaCha12Rng;
+pub use rand_core::{RngCore, SeedableRng};
+pub use rand_xoshiro::Xoshiro256PlusPlus;
+use serde::{Deserialize, Serialize};
+
+/// A generator algorithm, together with the version of its output stream.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RngBackend {
+    ChaCha12,
+    Xoshiro256PlusPlus,
+}
+
+impl RngBackend {
+    pub const ALL: [RngBackend; 2] = [RngBackend::ChaCha12, RngBackend::Xoshiro256PlusPlus];
+
+    /// The name recorded with results and saved inputs. It changes whenever
+    /// the output stream for a given seed does.
+    pub const fn name(self) -> &'static str {
+        match self {
+            RngBackend::ChaCha12 => "chacha12-v1",
+            RngBackend::Xoshiro256PlusPlus => "xoshiro256pp-v1",
+        }
+    }
+}
+
+impl fmt::Display for RngBackend {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for RngBackend {
+    type Err = String;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|backend| backend.name() == name)
+            .ok_or_else(|| {
+                let names: Vec<_> = Self::ALL.iter().map(|backend| backend.name()).collect();
+                format!("unknown generator `{name}`, expected one of {names:?}")
+            })
+    }
+}
+
+/// A seedable generator whose output stream is fixed for a given [NAME].
+///
+/// [NAME]: SuiteRng::NAME
+pub trait SuiteRng: RngCore + SeedableRng {
+    const BACKEND: RngBackend;
+
+    /// Identifies the algorithm and the version of its output stream.
+    const NAME: &'static str = Self::BACKEND.name();
+}
+
+impl SuiteRng for ChaCha12Rng {
+    const BACKEND: RngBackend = RngBackend::ChaCha12;
+}
+
+impl SuiteRng for Xoshiro256PlusPlus {
+    const BACKEND: RngBackend = RngBackend::Xoshiro256PlusPlus;
+}
+
+/// The generator used when none is selected.
+///
+/// This is the algorithm behind `StdRng` in rand 0.9, so inputs are unchanged
+/// from when the benchmarks used `StdRng` directly.
+pub type DefaultRng = ChaCha12Rng;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn names_round_trip() {
+        for backend in RngBackend::ALL {
+            assert_eq!(backend.name().parse(), Ok(backend));
+        }
+        assert!("stdrng".parse::<RngBackend>().is_err());
+    }
+
+    #[test]
+    fn streams_are_fixed() {
+        // Changing either stream changes every input and journal, and must
+        // come with a new name.
+        assert_eq!(
+            ChaCha12Rng::seed_from_u64(1337).next_u64(),
+            12090749038398272714
+        );
+        assert_eq!(
+            Xoshiro256PlusPlus::seed_from_u64(1337).next_u64(),
+            8156589452691600790
+        );
+    }
+}
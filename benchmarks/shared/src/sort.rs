@@ -0,0 +1,201 @@
+// Copyright 2025 RISC Zero, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortAlgorithm {
+    /// LSD radix sort, one byte per pass.
+    Radix,
+    /// Bottom-up merge sort.
+    Merge,
+    /// In-place quicksort with median-of-three pivots.
+    Quick,
+}
+
+impl SortAlgorithm {
+    pub fn name(&self) -> &'static str {
+        match self {
+            SortAlgorithm::Radix => "radix",
+            SortAlgorithm::Merge => "merge",
+            SortAlgorithm::Quick => "quick",
+        }
+    }
+
+    pub fn sort(&self, values: &mut [u32]) {
+        match self {
+            SortAlgorithm::Radix => radix_sort(values),
+            SortAlgorithm::Merge => merge_sort(values),
+            SortAlgorithm::Quick => quick_sort(values),
+        }
+    }
+}
+
+fn radix_sort(values: &mut [u32]) {
+    let mut buf = vec![0u32; values.len()];
+    for shift in (0..32).step_by(8) {
+        let mut offsets = [0usize; 257];
+        for value in values.iter() {
+            offsets[((value >> shift) & 0xff) as usize + 1] += 1;
+        }
+        for i in 1..offsets.len() {
+            offsets[i] += offsets[i - 1];
+        }
+        for value in values.iter() {
+            let bucket = &mut offsets[((value >> shift) & 0xff) as usize];
+            buf[*bucket] = *value;
+            *bucket += 1;
+        }
+        values.copy_from_slice(&buf);
+    }
+}
+
+fn merge_sort(values: &mut [u32]) {
+    let len = values.len();
+    let mut buf = vec![0u32; len];
+    let mut width = 1;
+    while width < len {
+        for start in (0..len).step_by(2 * width) {
+            let mid = (start + width).min(len);
+            let end = (start + 2 * width).min(len);
+            let (mut i, mut j) = (start, mid);
+            for slot in buf[start..end].iter_mut() {
+                if j >= end || (i < mid && values[i] <= values[j]) {
+                    *slot = values[i];
+                    i += 1;
+                } else {
+                    *slot = values[j];
+                    j += 1;
+                }
+            }
+        }
+        values.copy_from_slice(&buf);
+        width *= 2;
+    }
+}
+
+fn quick_sort(mut values: &mut [u32]) {
+    while values.len() > 1 {
+        let last = values.len() - 1;
+        let mid = last / 2;
+
+        // Move the median of the first, middle and last elements to the end.
+        if values[mid] < values[0] {
+            values.swap(mid, 0);
+        }
+        if values[last] < values[0] {
+            values.swap(last, 0);
+        }
+        if values[mid] < values[last] {
+            values.swap(mid, last);
+        }
+
+        let pivot = values[last];
+        let mut store = 0;
+        for i in 0..last {
+            if values[i] < pivot {
+                values.swap(i, store);
+                store += 1;
+            }
+        }
+        values.swap(store, last);
+
+        // Recurse into the smaller side to bound the stack depth.
+        let (left, right) = values.split_at_mut(store);
+        let right = &mut right[1..];
+        if left.len() < right.len() {
+            quick_sort(left);
+            values = right;
+        } else {
+            quick_sort(right);
+            values = left;
+        }
+    }
+}
+
+/// The Mersenne prime 2^61 - 1, used as the modulus of [multiset_hash].
+const MODULUS: u64 = (1 << 61) - 1;
+
+/// Evaluates the polynomial `prod(challenge - v)` over all `values` modulo
+/// 2^61 - 1.
+///
+/// Two lists that are permutations of each other always produce the same
+/// hash. For lists that are not, the hashes collide with probability at most
+/// `len / 2^61` over a random choice of `challenge`.
+pub fn multiset_hash(values: &[u32], challenge: u64) -> u64 {
+    let challenge = challenge % MODULUS;
+    values.iter().fold(1u64, |acc, value| {
+        let term = (challenge + MODULUS - *value as u64) % MODULUS;
+        ((acc as u128 * term as u128) % MODULUS as u128) as u64
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::Rng;
+
+    use crate::rng::{ChaCha12Rng, SeedableRng};
+
+    const ALGORITHMS: [SortAlgorithm; 3] = [
+        SortAlgorithm::Radix,
+        SortAlgorithm::Merge,
+        SortAlgorithm::Quick,
+    ];
+
+    #[test]
+    fn sorts_match_std() {
+        let mut rng = ChaCha12Rng::seed_from_u64(12345);
+        let random: Vec<u32> = (0..1000).map(|_| rng.random()).collect();
+        let inputs = [
+            vec![],
+            vec![1],
+            vec![2, 1],
+            vec![5; 10],
+            (0..100).collect(),
+            (0..100).rev().collect(),
+            random.iter().map(|x| x % 7).collect(),
+            random,
+        ];
+
+        for input in inputs.iter() {
+            let mut expected = input.clone();
+            expected.sort_unstable();
+            for algorithm in ALGORITHMS {
+                let mut actual = input.clone();
+                algorithm.sort(&mut actual);
+                assert_eq!(actual, expected, "{}", algorithm.name());
+            }
+        }
+    }
+
+    #[test]
+    fn multiset_hash_detects_changes() {
+        let values = [3, 1, 4, 1, 5, 9, 2, 6];
+        let challenge = 0x1234_5678_9abc_def0;
+        let mut permuted = values;
+        permuted.reverse();
+        assert_eq!(
+            multiset_hash(&values, challenge),
+            multiset_hash(&permuted, challenge)
+        );
+
+        let mut changed = values;
+        changed[3] = 7;
+        assert_ne!(
+            multiset_hash(&values, challenge),
+            multiset_hash(&changed, challenge)
+        );
+    }
+}
@@ -0,0 +1,75 @@
+// Copyright 2025 RISC Zero, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Aggregation of the receipts of many jobs into a single receipt.
+
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use risc0_zkvm::{
+    get_prover_server,
+    sha::{Digest, Impl, Sha256},
+    ExecutorEnv, ExecutorImpl, ProverOpts, Receipt, VerifierContext,
+};
+
+use crate::{check_exit, Job};
+
+impl Job {
+    fn prove_succinct(&self) -> Result<Receipt> {
+        let (session, _, _) = self.execute()?;
+        let prover = get_prover_server(&ProverOpts::succinct())?;
+        let receipt = prover
+            .prove_session(&VerifierContext::default(), &session)?
+            .receipt;
+        receipt.verify(self.image_id)?;
+        Ok(receipt)
+    }
+}
+
+/// Proves every job and then a single receipt that verifies all of them,
+/// committing the image ID and journal digest of each. The aggregate receipt
+/// is written as JSON to `out_path`.
+///
+/// If a `beacon` value is given, such as a recent drand round, its SHA-256
+/// digest is committed as well. Since the value could not be known in advance,
+/// this shows that the aggregate was proven after it was published.
+pub fn run_aggregate(out_path: &Path, jobs: Vec<Job>, beacon: Option<&str>) -> Result<Receipt> {
+    tracing::info!("Aggregating {} jobs", jobs.len());
+
+    let mut env = ExecutorEnv::builder();
+    let mut claims: Vec<(Digest, Vec<u8>)> = Vec::new();
+    for job in jobs {
+        println!("Proving {}", job.name);
+        let receipt = job
+            .prove_succinct()
+            .with_context(|| format!("proving {}", job.name))?;
+        claims.push((job.image_id, receipt.journal.bytes.clone()));
+        env.add_assumption(receipt);
+    }
+
+    println!("Proving aggregate of {} receipts", claims.len());
+    let beacon = beacon.map(|x| *Impl::hash_bytes(x.as_bytes()));
+    let env = env.write(&(beacon, claims))?.build()?;
+    let session = ExecutorImpl::from_elf(env, risc0_benchmark_methods::AGGREGATE_ELF)?.run()?;
+    check_exit(&session)?;
+    let prover = get_prover_server(&ProverOpts::succinct())?;
+    let receipt = prover
+        .prove_session(&VerifierContext::default(), &session)?
+        .receipt;
+    receipt.verify(risc0_benchmark_methods::AGGREGATE_ID)?;
+
+    std::fs::write(out_path, serde_json::to_vec(&receipt)?)?;
+    println!("Wrote aggregate receipt to {}", out_path.display());
+    Ok(receipt)
+}
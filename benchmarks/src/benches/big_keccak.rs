@@ -12,13 +12,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use rand::{rngs::StdRng, RngCore, SeedableRng};
 use risc0_zkvm::serde::to_vec;
 
-use crate::Job;
+use crate::{
+    rng::{suite_rng, SuiteRng},
+    Job,
+};
 
-pub fn new_jobs() -> Vec<Job> {
-    let mut rand = StdRng::seed_from_u64(1337);
+pub fn new_jobs<R: SuiteRng>() -> Vec<Job> {
+    let mut rand = suite_rng::<R>();
     let mut jobs = Vec::new();
     for job_size in [1024, 2048, 4096, 8192] {
         let mut guest_input = vec![0; job_size];
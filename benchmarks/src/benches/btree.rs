@@ -0,0 +1,71 @@
+// Copyright 2025 RISC Zero, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use anyhow::Result;
+use rand::Rng;
+use risc0_benchmark_lib::btree::{BTreeIndex, BTreeQuery, IndexError};
+use risc0_zkvm::{serde::to_vec, sha::Digest, Journal};
+
+use crate::{
+    journal::{decode_exact, DecodedJournal},
+    rng::{suite_rng, SuiteRng},
+    Job,
+};
+
+const FANOUT: usize = 32;
+const NUM_GETS: usize = 64;
+const NUM_RANGES: usize = 16;
+
+pub fn new_jobs<R: SuiteRng>() -> Vec<Job> {
+    let mut rand = suite_rng::<R>();
+    let mut jobs = Vec::new();
+    for num_entries in [1000u64, 10000, 100000] {
+        // Spread keys out so that about half of the random lookups miss.
+        let entries: Vec<(u64, u64)> = (0..num_entries)
+            .map(|i| (i * 2 + rand.random_range(0..2), rand.random()))
+            .collect();
+        let index = BTreeIndex::from_sorted(&entries, FANOUT);
+
+        let max_key = num_entries * 2;
+        let range_width = max_key / 100;
+        let mut queries: Vec<BTreeQuery> = (0..NUM_GETS)
+            .map(|_| BTreeQuery::Get(rand.random_range(0..max_key)))
+            .collect();
+        queries.extend((0..NUM_RANGES).map(|_| {
+            let start = rand.random_range(0..max_key);
+            BTreeQuery::Range(start, start + range_width)
+        }));
+
+        jobs.push(Job::new(
+            format!("btree-{num_entries}"),
+            risc0_benchmark_methods::BTREE_ELF,
+            risc0_benchmark_methods::BTREE_ID.into(),
+            to_vec(&(index, queries)).unwrap(),
+            num_entries as usize,
+        ));
+    }
+    jobs
+}
+
+/// Decodes the number of queries and the digest of their results, or the
+/// reason the index was rejected.
+pub fn decode(journal: &Journal) -> Result<DecodedJournal> {
+    let result: Result<(u32, Digest), IndexError> = decode_exact(journal)?;
+    Ok(match result {
+        Ok((queries, results)) => DecodedJournal::default()
+            .with("queries", queries)
+            .with("results", results),
+        Err(err) => DecodedJournal::default().with("error", format!("{err:?}")),
+    })
+}
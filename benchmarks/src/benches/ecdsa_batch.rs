@@ -0,0 +1,68 @@
+// Copyright 2025 RISC Zero, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use anyhow::Result;
+use k256::ecdsa::{signature::Signer, Signature, SigningKey};
+use risc0_zkvm::{serde::to_vec, sha::Digest, Journal};
+
+use crate::{
+    journal::{decode_exact, DecodedJournal},
+    rng::{key_bytes, suite_rng, SuiteRng},
+    Job,
+};
+
+pub fn new_jobs<R: SuiteRng>() -> Vec<Job> {
+    let mut rand = suite_rng::<R>();
+    let mut jobs = Vec::new();
+    for batch_size in [1, 4, 16] {
+        let batch: Vec<_> = (0..batch_size)
+            .map(|i| {
+                // Generate a random secp256k1 keypair and sign a unique message.
+                // Only a negligible fraction of byte strings are not valid
+                // scalars.
+                let signing_key = SigningKey::from_bytes(&key_bytes(&mut rand).into()).unwrap();
+                let verifying_key = signing_key.verifying_key().to_encoded_point(true);
+                let mut message =
+                    format!("Message {i} of a batch verified within the zkVM").into_bytes();
+                let signature: Signature = signing_key.sign(&message);
+
+                // Tamper with every fourth message so that the batch contains
+                // invalid signatures as well.
+                if i % 4 == 3 {
+                    message.push(b'!');
+                }
+                (verifying_key, message, signature)
+            })
+            .collect();
+
+        jobs.push(Job::new(
+            format!("ecdsa_batch-{batch_size}"),
+            risc0_benchmark_methods::ECDSA_BATCH_ELF,
+            risc0_benchmark_methods::ECDSA_BATCH_ID.into(),
+            to_vec(&batch).unwrap(),
+            batch_size,
+        ));
+    }
+    jobs
+}
+
+/// Decodes the size of the batch, the number of valid signatures and the
+/// digest of the signed messages.
+pub fn decode(journal: &Journal) -> Result<DecodedJournal> {
+    let (signatures, valid, messages): (u32, u32, Digest) = decode_exact(journal)?;
+    Ok(DecodedJournal::default()
+        .with("signatures", signatures)
+        .with("valid", valid)
+        .with("messages", messages))
+}
@@ -12,15 +12,23 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use k256::ecdsa::{signature::Signer, Signature, SigningKey};
-use rand_core::OsRng;
-use risc0_zkvm::serde::to_vec;
+use anyhow::Result;
+use k256::{
+    ecdsa::{signature::Signer, Signature, SigningKey},
+    EncodedPoint,
+};
+use risc0_zkvm::{serde::to_vec, Journal};
 
-use crate::Job;
+use crate::{
+    journal::{decode_exact, hex, DecodedJournal},
+    rng::{key_bytes, suite_rng, SuiteRng},
+    Job,
+};
 
-pub fn new_jobs() -> Vec<Job> {
-    // Generate a random secp256k1 keypair and sign the message.
-    let signing_key = SigningKey::random(&mut OsRng);
+pub fn new_jobs<R: SuiteRng>() -> Vec<Job> {
+    // Generate a random secp256k1 keypair and sign the message. Only a
+    // negligible fraction of byte strings are not valid scalars.
+    let signing_key = SigningKey::from_bytes(&key_bytes(&mut suite_rng::<R>()).into()).unwrap();
     let verifying_key = signing_key.verifying_key().to_encoded_point(true);
     let message = b"This is a message that will be signed, and verified within the zkVM".to_vec();
     let signature: Signature = signing_key.sign(&message);
@@ -35,3 +43,11 @@ pub fn new_jobs() -> Vec<Job> {
         1,
     )]
 }
+
+/// Decodes the verifying key and the message that was signed.
+pub fn decode(journal: &Journal) -> Result<DecodedJournal> {
+    let (key, message): (EncodedPoint, Vec<u8>) = decode_exact(journal)?;
+    Ok(DecodedJournal::default()
+        .with("key", hex(key.as_bytes()))
+        .with("message", hex(&message)))
+}
@@ -12,15 +12,19 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use anyhow::Result;
 use ed25519_dalek::{Signature, Signer, SigningKey, VerifyingKey};
-use rand_core::OsRng;
-use risc0_zkvm::serde::to_vec;
+use risc0_zkvm::{serde::to_vec, Journal};
 
-use crate::Job;
+use crate::{
+    journal::{decode_exact, hex, DecodedJournal},
+    rng::{key_bytes, suite_rng, SuiteRng},
+    Job,
+};
 
-pub fn new_jobs() -> Vec<Job> {
+pub fn new_jobs<R: SuiteRng>() -> Vec<Job> {
     // Generate a random ed25519 keypair and sign the message.
-    let signing_key: SigningKey = SigningKey::generate(&mut OsRng);
+    let signing_key: SigningKey = SigningKey::from_bytes(&key_bytes(&mut suite_rng::<R>()));
     let verifying_key: VerifyingKey = signing_key.verifying_key();
     let message = b"This is a message that will be signed, and verified within the zkVM".to_vec();
     let signature: Signature = signing_key.sign(&message);
@@ -41,3 +45,11 @@ pub fn new_jobs() -> Vec<Job> {
         1,
     )]
 }
+
+/// Decodes the verifying key and the message that was signed.
+pub fn decode(journal: &Journal) -> Result<DecodedJournal> {
+    let (key, message): ([u8; 32], Vec<u8>) = decode_exact(journal)?;
+    Ok(DecodedJournal::default()
+        .with("key", hex(&key))
+        .with("message", hex(&message)))
+}
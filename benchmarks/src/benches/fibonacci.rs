@@ -12,9 +12,13 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use risc0_zkvm::serde::to_vec;
+use anyhow::Result;
+use risc0_zkvm::{serde::to_vec, Journal};
 
-use crate::Job;
+use crate::{
+    journal::{decode_exact, DecodedJournal},
+    Job,
+};
 
 pub fn new_jobs() -> Vec<Job> {
     let mut jobs = Vec::new();
@@ -29,3 +33,9 @@ pub fn new_jobs() -> Vec<Job> {
     }
     jobs
 }
+
+/// Decodes the computed Fibonacci number.
+pub fn decode(journal: &Journal) -> Result<DecodedJournal> {
+    let answer: u64 = decode_exact(journal)?;
+    Ok(DecodedJournal::default().with("answer", answer))
+}
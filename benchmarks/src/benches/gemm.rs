@@ -0,0 +1,51 @@
+// Copyright 2025 RISC Zero, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use anyhow::Result;
+use rand::Rng;
+use risc0_zkvm::{serde::to_vec, sha::Digest, Journal};
+
+use crate::{
+    journal::{decode_exact, DecodedJournal},
+    rng::{suite_rng, SuiteRng},
+    Job,
+};
+
+const BLOCK_SIZE: u32 = 8;
+
+pub fn new_jobs<R: SuiteRng>() -> Vec<Job> {
+    let mut rand = suite_rng::<R>();
+    let mut jobs = Vec::new();
+    for n in [16u32, 32, 64] {
+        let len = (n * n) as usize;
+        let a: Vec<i32> = (0..len).map(|_| rand.random()).collect();
+        let b: Vec<i32> = (0..len).map(|_| rand.random()).collect();
+        jobs.push(Job::new(
+            format!("gemm-{n}"),
+            risc0_benchmark_methods::GEMM_ELF,
+            risc0_benchmark_methods::GEMM_ID.into(),
+            to_vec(&(n, BLOCK_SIZE, a, b)).unwrap(),
+            n as usize,
+        ));
+    }
+    jobs
+}
+
+/// Decodes the dimension of the matrices and the digest of their product.
+pub fn decode(journal: &Journal) -> Result<DecodedJournal> {
+    let (n, product): (u32, Digest) = decode_exact(journal)?;
+    Ok(DecodedJournal::default()
+        .with("n", n)
+        .with("product", product))
+}
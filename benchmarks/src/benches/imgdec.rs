@@ -0,0 +1,72 @@
+// Copyright 2025 RISC Zero, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use anyhow::Result;
+use png::{BitDepth, ColorType, Encoder};
+use rand::Rng;
+use risc0_zkvm::{serde::to_vec, Journal};
+
+use crate::{
+    journal::{decode_exact, DecodedJournal},
+    rng::{suite_rng, SuiteRng},
+    Job,
+};
+
+pub fn new_jobs<R: SuiteRng>() -> Vec<Job> {
+    let mut rand = suite_rng::<R>();
+    let mut jobs = Vec::new();
+    for dim in [64u32, 128, 256] {
+        // Noisy diagonal stripes, which compress poorly enough to make decoding
+        // non-trivial.
+        let pixels: Vec<u8> = (0..dim * dim)
+            .flat_map(|i| {
+                let (x, y) = (i % dim, i / dim);
+                let base = ((x * 7 + y * 3) % dim * 255 / dim) as u8;
+                [
+                    base.saturating_add(rand.random_range(0..32)),
+                    base,
+                    255 - base,
+                ]
+            })
+            .collect();
+
+        let mut image = Vec::new();
+        let mut encoder = Encoder::new(&mut image, dim, dim);
+        encoder.set_color(ColorType::Rgb);
+        encoder.set_depth(BitDepth::Eight);
+        encoder
+            .write_header()
+            .unwrap()
+            .write_image_data(&pixels)
+            .unwrap();
+
+        jobs.push(Job::new(
+            format!("imgdec-{dim}"),
+            risc0_benchmark_methods::IMGDEC_ELF,
+            risc0_benchmark_methods::IMGDEC_ID.into(),
+            to_vec(&image).unwrap(),
+            (dim * dim) as usize,
+        ));
+    }
+    jobs
+}
+
+/// Decodes the dimensions and the perceptual hash of the image.
+pub fn decode(journal: &Journal) -> Result<DecodedJournal> {
+    let (width, height, dhash): (u32, u32, u64) = decode_exact(journal)?;
+    Ok(DecodedJournal::default()
+        .with("width", width)
+        .with("height", height)
+        .with("dhash", format!("{dhash:016x}")))
+}
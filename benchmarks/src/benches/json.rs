@@ -0,0 +1,80 @@
+// Copyright 2025 RISC Zero, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use anyhow::Result;
+use rand::Rng;
+use risc0_zkvm::{serde::to_vec, sha::Digest, Journal};
+use serde_json::json;
+
+use crate::{
+    journal::{decode_exact, DecodedJournal},
+    rng::{suite_rng, SuiteRng},
+    Job,
+};
+
+const NUM_QUERIES: usize = 32;
+
+pub fn new_jobs<R: SuiteRng>() -> Vec<Job> {
+    let mut rand = suite_rng::<R>();
+    let mut jobs = Vec::new();
+    for num_records in [100, 1000, 10000] {
+        let records: Vec<_> = (0..num_records)
+            .map(|id| {
+                json!({
+                    "id": id,
+                    "name": format!("user-{id}"),
+                    "balance": rand.random_range(0..1_000_000u64),
+                    "active": rand.random_bool(0.5),
+                    "address": {
+                        "city": format!("city-{}", rand.random_range(0..100u32)),
+                        "zip": format!("{:05}", rand.random_range(0..100_000u32)),
+                    },
+                    "tags": ["alpha", "beta", "gamma"],
+                })
+            })
+            .collect();
+        let document = json!({ "records": records }).to_string();
+
+        // Query a mix of scalar fields, nested objects and missing paths.
+        let queries: Vec<String> = (0..NUM_QUERIES)
+            .map(|i| {
+                let record = i * num_records / NUM_QUERIES;
+                match i % 4 {
+                    0 => format!("/records/{record}/balance"),
+                    1 => format!("/records/{record}/address"),
+                    2 => format!("/records/{record}/tags/1"),
+                    _ => format!("/records/{record}/missing"),
+                }
+            })
+            .collect();
+
+        let size = document.len();
+        jobs.push(Job::new(
+            format!("json-{num_records}"),
+            risc0_benchmark_methods::JSON_ELF,
+            risc0_benchmark_methods::JSON_ID.into(),
+            to_vec(&(document, queries)).unwrap(),
+            size,
+        ));
+    }
+    jobs
+}
+
+/// Decodes the number of queries and the digest of the extracted values.
+pub fn decode(journal: &Journal) -> Result<DecodedJournal> {
+    let (queries, values): (u32, Digest) = decode_exact(journal)?;
+    Ok(DecodedJournal::default()
+        .with("queries", queries)
+        .with("values", values))
+}
@@ -0,0 +1,78 @@
+// Copyright 2025 RISC Zero, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use anyhow::Result;
+use rand::Rng;
+use risc0_benchmark_lib::maxflow::{FlowNetwork, NetworkError, ValidationPolicy};
+use risc0_zkvm::{serde::to_vec, sha::Digest, Journal};
+
+use crate::{
+    journal::{decode_exact, DecodedJournal},
+    rng::{suite_rng, SuiteRng},
+    Job,
+};
+
+const EDGES_PER_NODE: u32 = 8;
+
+pub fn new_jobs<R: SuiteRng>() -> Vec<Job> {
+    let mut rand = suite_rng::<R>();
+    let mut jobs = Vec::new();
+    for num_nodes in [100u32, 500, 2000] {
+        let edges: Vec<(u32, u32, u64)> = (0..num_nodes * EDGES_PER_NODE)
+            .map(|_| {
+                (
+                    rand.random_range(0..num_nodes),
+                    rand.random_range(0..num_nodes),
+                    rand.random_range(1..=100),
+                )
+            })
+            .collect();
+        let num_edges = edges.len();
+        let network = FlowNetwork {
+            num_nodes,
+            source: 0,
+            sink: num_nodes - 1,
+            edges,
+        };
+
+        jobs.push(Job::new(
+            format!("maxflow-{num_nodes}"),
+            risc0_benchmark_methods::MAXFLOW_ELF,
+            risc0_benchmark_methods::MAXFLOW_ID.into(),
+            to_vec(&(ValidationPolicy::default(), network)).unwrap(),
+            num_edges,
+        ));
+    }
+    jobs
+}
+
+/// Decodes the validation policy, the digest of the network and either the
+/// flow value with the digest of the cut edges, or the reason the network was
+/// rejected.
+pub fn decode(journal: &Journal) -> Result<DecodedJournal> {
+    let (policy, network, result): (
+        ValidationPolicy,
+        Digest,
+        Result<(u64, Digest), NetworkError>,
+    ) = decode_exact(journal)?;
+    let decoded = DecodedJournal::default()
+        .with("max_nodes", policy.max_nodes)
+        .with("max_capacity", policy.max_capacity)
+        .with("allow_parallel_edges", policy.allow_parallel_edges)
+        .with("network", network);
+    Ok(match result {
+        Ok((flow, cut)) => decoded.with("flow", flow).with("cut", cut),
+        Err(err) => decoded.with("error", format!("{err:?}")),
+    })
+}
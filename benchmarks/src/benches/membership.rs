@@ -12,10 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use anyhow::Result;
 use risc0_benchmark_lib::generate_mock_proof;
-use risc0_zkvm::serde::to_vec;
+use risc0_zkvm::{serde::to_vec, sha::Digest, Journal};
 
-use crate::Job;
+use crate::{
+    journal::{decode_exact, DecodedJournal},
+    Job,
+};
 
 pub fn new_jobs() -> Vec<Job> {
     let mut jobs = Vec::new();
@@ -31,3 +35,11 @@ pub fn new_jobs() -> Vec<Job> {
     }
     jobs
 }
+
+/// Decodes the leaf and the root of the verified path.
+pub fn decode(journal: &Journal) -> Result<DecodedJournal> {
+    let (leaf, root): (Digest, Digest) = decode_exact(journal)?;
+    Ok(DecodedJournal::default()
+        .with("leaf", leaf)
+        .with("root", root))
+}
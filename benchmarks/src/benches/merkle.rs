@@ -0,0 +1,61 @@
+// Copyright 2025 RISC Zero, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use anyhow::Result;
+use risc0_zkvm::{serde::to_vec, sha::Digest, Journal};
+
+use crate::{
+    journal::{decode_exact, DecodedJournal},
+    rng::{suite_rng, SuiteRng},
+    Job,
+};
+
+const CHUNK_SIZE: usize = 1024;
+
+pub fn new_jobs<R: SuiteRng>() -> Vec<Job> {
+    let mut rand = suite_rng::<R>();
+    let mut jobs = Vec::new();
+    for num_chunks in [64, 256, 1024] {
+        let mut data = vec![0u8; num_chunks * CHUNK_SIZE];
+        rand.fill_bytes(&mut data);
+
+        for (backend, accelerated) in [("accel", true), ("soft", false)] {
+            // The header is followed by the raw chunk data, which the guest
+            // reads one chunk at a time.
+            let mut guest_input =
+                to_vec(&(accelerated, CHUNK_SIZE as u32, num_chunks as u32)).unwrap();
+            guest_input.extend(
+                data.chunks_exact(4)
+                    .map(|word| u32::from_le_bytes(word.try_into().unwrap())),
+            );
+
+            jobs.push(Job::new(
+                format!("merkle-{backend}-{num_chunks}"),
+                risc0_benchmark_methods::MERKLE_ELF,
+                risc0_benchmark_methods::MERKLE_ID.into(),
+                guest_input,
+                data.len(),
+            ));
+        }
+    }
+    jobs
+}
+
+/// Decodes the number of chunks and the root of the tree over them.
+pub fn decode(journal: &Journal) -> Result<DecodedJournal> {
+    let (chunks, root): (u32, Digest) = decode_exact(journal)?;
+    Ok(DecodedJournal::default()
+        .with("chunks", chunks)
+        .with("root", root))
+}
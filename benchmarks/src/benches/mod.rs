@@ -16,12 +16,79 @@ pub mod big_blake2b;
 pub mod big_blake3;
 pub mod big_keccak;
 pub mod big_sha2;
+pub mod btree;
+pub mod ecdsa_batch;
 pub mod ecdsa_verify;
 pub mod ed25519_verify;
 pub mod fibonacci;
+pub mod gemm;
+pub mod imgdec;
 pub mod iter_blake2b;
 pub mod iter_blake3;
 pub mod iter_keccak;
 pub mod iter_sha2;
+pub mod json;
+pub mod maxflow;
 pub mod membership;
+pub mod merkle;
+pub mod montecarlo;
+pub mod rsa_modpow;
+pub mod sort;
 pub mod sudoku;
+
+use anyhow::{anyhow, Result};
+use risc0_benchmark_methods::*;
+use risc0_zkvm::{sha::Digest, Journal};
+
+use crate::journal::{decode_digest, DecodedJournal, Decoder};
+
+/// The guest of every benchmark, with its image ID and the decoder of its
+/// journal.
+const GUESTS: &[(&[u8], [u32; 8], Decoder)] = &[
+    (BIG_BLAKE2B_ELF, BIG_BLAKE2B_ID, decode_digest),
+    (BIG_BLAKE3_ELF, BIG_BLAKE3_ID, decode_digest),
+    (BIG_KECCAK_ELF, BIG_KECCAK_ID, decode_digest),
+    (BIG_SHA2_ELF, BIG_SHA2_ID, decode_digest),
+    (BTREE_ELF, BTREE_ID, btree::decode),
+    (ECDSA_BATCH_ELF, ECDSA_BATCH_ID, ecdsa_batch::decode),
+    (ECDSA_VERIFY_ELF, ECDSA_VERIFY_ID, ecdsa_verify::decode),
+    (
+        ED25519_VERIFY_ELF,
+        ED25519_VERIFY_ID,
+        ed25519_verify::decode,
+    ),
+    (FIBONACCI_ELF, FIBONACCI_ID, fibonacci::decode),
+    (GEMM_ELF, GEMM_ID, gemm::decode),
+    (IMGDEC_ELF, IMGDEC_ID, imgdec::decode),
+    (ITER_BLAKE2B_ELF, ITER_BLAKE2B_ID, decode_digest),
+    (ITER_BLAKE3_ELF, ITER_BLAKE3_ID, decode_digest),
+    (ITER_KECCAK_ELF, ITER_KECCAK_ID, decode_digest),
+    (ITER_SHA2_ELF, ITER_SHA2_ID, decode_digest),
+    (JSON_ELF, JSON_ID, json::decode),
+    (MAXFLOW_ELF, MAXFLOW_ID, maxflow::decode),
+    (MEMBERSHIP_ELF, MEMBERSHIP_ID, membership::decode),
+    (MERKLE_ELF, MERKLE_ID, merkle::decode),
+    (MONTECARLO_ELF, MONTECARLO_ID, montecarlo::decode),
+    (RSA_MODPOW_ELF, RSA_MODPOW_ID, rsa_modpow::decode),
+    (SORT_ELF, SORT_ID, sort::decode),
+    (SUDOKU_ELF, SUDOKU_ID, decode_digest),
+];
+
+/// Returns the ELF of the benchmark guest with the given image ID, without
+/// generating the inputs of any benchmark.
+pub fn guest_elf(image_id: Digest) -> Option<&'static [u8]> {
+    GUESTS
+        .iter()
+        .find(|(_, id, _)| Digest::from(*id) == image_id)
+        .map(|(elf, _, _)| *elf)
+}
+
+/// Decodes the journal committed by the benchmark guest with the given image
+/// ID.
+pub fn decode_journal(image_id: Digest, journal: &Journal) -> Result<DecodedJournal> {
+    let (_, _, decode) = GUESTS
+        .iter()
+        .find(|(_, id, _)| Digest::from(*id) == image_id)
+        .ok_or_else(|| anyhow!("no guest with image ID {image_id}"))?;
+    decode(journal)
+}
@@ -0,0 +1,65 @@
+// Copyright 2025 RISC Zero, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use anyhow::Result;
+use risc0_benchmark_lib::montecarlo::{to_fixed, OptionParams, OptionPrice};
+use risc0_zkvm::{serde::to_vec, Journal};
+
+use crate::{
+    journal::{decode_exact, DecodedJournal},
+    rng::{SuiteRng, SEED},
+    Job,
+};
+
+const STEPS: u32 = 64;
+
+pub fn new_jobs<R: SuiteRng>() -> Vec<Job> {
+    // A one year at-the-money call with a 5% rate and 20% volatility.
+    let dt = 1.0 / STEPS as f64;
+    let mut jobs = Vec::new();
+    for paths in [100, 1000, 10000] {
+        let params = OptionParams {
+            rng: R::BACKEND,
+            seed: SEED,
+            paths,
+            steps: STEPS,
+            spot: to_fixed(100.0),
+            strike: to_fixed(100.0),
+            drift: to_fixed(0.05 * dt),
+            volatility: to_fixed(0.2 * dt.sqrt()),
+            discount: to_fixed((-0.05f64).exp()),
+        };
+        jobs.push(Job::new(
+            format!("montecarlo-{paths}"),
+            risc0_benchmark_methods::MONTECARLO_ELF,
+            risc0_benchmark_methods::MONTECARLO_ID.into(),
+            to_vec(&params).unwrap(),
+            paths as usize,
+        ));
+    }
+    jobs
+}
+
+/// Decodes the generator and the number of paths of the run, and the price
+/// with its confidence interval in Q32.32 fixed point.
+pub fn decode(journal: &Journal) -> Result<DecodedJournal> {
+    let (params, price): (OptionParams, OptionPrice) = decode_exact(journal)?;
+    Ok(DecodedJournal::default()
+        .with("rng", params.rng)
+        .with("seed", params.seed)
+        .with("paths", params.paths)
+        .with("price", price.price)
+        .with("ci_low", price.ci_low)
+        .with("ci_high", price.ci_high))
+}
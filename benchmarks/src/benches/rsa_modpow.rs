@@ -0,0 +1,62 @@
+// Copyright 2025 RISC Zero, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use anyhow::Result;
+use num_bigint::BigUint;
+use rand::RngCore;
+use risc0_zkvm::{serde::to_vec, Journal};
+
+use crate::{
+    journal::{decode_exact, DecodedJournal},
+    rng::{suite_rng, SuiteRng},
+    Job,
+};
+
+pub fn new_jobs<R: SuiteRng>() -> Vec<Job> {
+    let mut rand = suite_rng::<R>();
+    let mut jobs = Vec::new();
+    for bits in [2048, 4096] {
+        // Use an odd modulus with the top bit set so that it has exactly `bits` bits.
+        let mut modulus = random_biguint(&mut rand, bits);
+        modulus.set_bit(bits as u64 - 1, true);
+        modulus.set_bit(0, true);
+        let base = random_biguint(&mut rand, bits) % &modulus;
+        // Both backends get the same input and must commit the same result.
+        for (backend, accelerated) in [("bigint2", true), ("num_bigint", false)] {
+            jobs.push(
+                Job::new(
+                    format!("rsa_modpow-{backend}-{bits}"),
+                    risc0_benchmark_methods::RSA_MODPOW_ELF,
+                    risc0_benchmark_methods::RSA_MODPOW_ID.into(),
+                    to_vec(&(accelerated, &base, &modulus)).unwrap(),
+                    bits,
+                )
+                .with_cross_check(format!("rsa_modpow-{bits}")),
+            );
+        }
+    }
+    jobs
+}
+
+fn random_biguint(rand: &mut impl RngCore, bits: usize) -> BigUint {
+    let mut bytes = vec![0u8; bits / 8];
+    rand.fill_bytes(&mut bytes);
+    BigUint::from_bytes_be(&bytes)
+}
+
+/// Decodes the result of the modular exponentiation.
+pub fn decode(journal: &Journal) -> Result<DecodedJournal> {
+    let result: BigUint = decode_exact(journal)?;
+    Ok(DecodedJournal::default().with("result", format!("{result:x}")))
+}
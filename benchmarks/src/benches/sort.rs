@@ -0,0 +1,56 @@
+// Copyright 2025 RISC Zero, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use anyhow::Result;
+use rand::Rng;
+use risc0_benchmark_lib::sort::SortAlgorithm;
+use risc0_zkvm::{serde::to_vec, sha::Digest, Journal};
+
+use crate::{
+    journal::{decode_exact, DecodedJournal},
+    rng::{suite_rng, SuiteRng},
+    Job,
+};
+
+pub fn new_jobs<R: SuiteRng>() -> Vec<Job> {
+    let mut rand = suite_rng::<R>();
+    let mut jobs = Vec::new();
+    for len in [1000, 10000, 100000] {
+        let values: Vec<u32> = (0..len).map(|_| rand.random()).collect();
+        for algorithm in [
+            SortAlgorithm::Radix,
+            SortAlgorithm::Merge,
+            SortAlgorithm::Quick,
+        ] {
+            jobs.push(Job::new(
+                format!("sort-{}-{len}", algorithm.name()),
+                risc0_benchmark_methods::SORT_ELF,
+                risc0_benchmark_methods::SORT_ID.into(),
+                to_vec(&(algorithm, &values)).unwrap(),
+                len,
+            ));
+        }
+    }
+    jobs
+}
+
+/// Decodes the algorithm, the length of the array and the digest of the
+/// sorted array.
+pub fn decode(journal: &Journal) -> Result<DecodedJournal> {
+    let (algorithm, len, sorted): (SortAlgorithm, u32, Digest) = decode_exact(journal)?;
+    Ok(DecodedJournal::default()
+        .with("algorithm", algorithm.name())
+        .with("len", len)
+        .with("sorted", sorted))
+}
@@ -0,0 +1,147 @@
+// Copyright 2025 RISC Zero, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Expected-result oracles, which turn the benchmarks into a correctness
+//! check by comparing the decoded journal of each job against the values
+//! recorded for it.
+
+use std::{collections::BTreeMap, path::Path};
+
+use anyhow::{anyhow, ensure, Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::{benches::decode_journal, journal::DecodedJournal, Job};
+
+/// One value that a job is expected to commit, as a row of an
+/// expected-results file.
+#[derive(Serialize, Deserialize)]
+struct ExpectedValue {
+    name: String,
+    rng: String,
+    field: String,
+    value: String,
+}
+
+impl Job {
+    /// Executes the job and decodes its journal.
+    fn decoded_journal(&self) -> Result<DecodedJournal> {
+        let (session, _, _) = self.execute()?;
+        let journal = session
+            .journal
+            .ok_or_else(|| anyhow!("session produced no journal"))?;
+        decode_journal(self.image_id, &journal)
+    }
+}
+
+/// Executes every job and writes the decoded values of its journal to a CSV
+/// file at `path`, one row per value, for later use with [check_expected].
+///
+/// A job whose guest fails is reported and left out of the file. Returns
+/// `true` if every job was recorded.
+pub fn record_expected(path: &Path, jobs: Vec<Job>) -> Result<bool> {
+    let mut out = csv::WriterBuilder::new()
+        .from_path(path)
+        .with_context(|| format!("failed to create {}", path.display()))?;
+    let recorded = crate::check_jobs("expected-result recording", jobs, |job| {
+        for (field, value) in job.decoded_journal()?.fields {
+            out.serialize(ExpectedValue {
+                name: job.name.clone(),
+                rng: job.rng.name().to_string(),
+                field: field.to_string(),
+                value,
+            })?;
+        }
+        Ok(())
+    });
+    out.flush()?;
+    Ok(recorded)
+}
+
+/// Executes every job and checks the decoded values of its journal against
+/// the ones recorded for the same job and generator in the CSV file at
+/// `path`.
+///
+/// Returns `true` if every job matched.
+pub fn check_expected(path: &Path, jobs: Vec<Job>) -> Result<bool> {
+    let mut expected: BTreeMap<(String, String), Vec<(String, String)>> = BTreeMap::new();
+    let mut reader = csv::Reader::from_path(path)
+        .with_context(|| format!("failed to open {}", path.display()))?;
+    for row in reader.deserialize() {
+        let row: ExpectedValue = row?;
+        expected
+            .entry((row.name, row.rng))
+            .or_default()
+            .push((row.field, row.value));
+    }
+
+    Ok(crate::check_jobs("expected-result check", jobs, |job| {
+        let want = expected
+            .get(&(job.name.clone(), job.rng.name().to_string()))
+            .ok_or_else(|| anyhow!("no expected result recorded for {}", job.rng))?;
+        let mismatches = compare(&job.decoded_journal()?, want);
+        ensure!(mismatches.is_empty(), "{}", mismatches.join(", "));
+        Ok(())
+    }))
+}
+
+/// Describes every value of `got` that differs from the recorded values in
+/// `want`, and every recorded value that is missing from it.
+fn compare(got: &DecodedJournal, want: &[(String, String)]) -> Vec<String> {
+    let mut mismatches = Vec::new();
+    for (field, value) in &got.fields {
+        match want.iter().find(|(name, _)| name == field) {
+            Some((_, expected)) if expected == value => {}
+            Some((_, expected)) => {
+                mismatches.push(format!("{field} is {value}, expected {expected}"))
+            }
+            None => mismatches.push(format!("{field} is {value}, expected none")),
+        }
+    }
+    for (field, expected) in want {
+        if !got.fields.iter().any(|(name, _)| name == field) {
+            mismatches.push(format!("{field} is missing, expected {expected}"));
+        }
+    }
+    mismatches
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compare_reports_each_field() {
+        let got = DecodedJournal::default().with("flow", 12).with("cut", "ab");
+        let want = |fields: &[(&str, &str)]| -> Vec<(String, String)> {
+            fields
+                .iter()
+                .map(|(field, value)| (field.to_string(), value.to_string()))
+                .collect()
+        };
+
+        assert!(compare(&got, &want(&[("flow", "12"), ("cut", "ab")])).is_empty());
+        assert_eq!(
+            compare(&got, &want(&[("flow", "13"), ("cut", "ab")])),
+            ["flow is 12, expected 13"]
+        );
+        assert_eq!(
+            compare(&got, &want(&[("error", "TooManyNodes")])),
+            [
+                "flow is 12, expected none",
+                "cut is ab, expected none",
+                "error is missing, expected TooManyNodes"
+            ]
+        );
+    }
+}
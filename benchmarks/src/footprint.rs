@@ -0,0 +1,122 @@
+// Copyright 2025 RISC Zero, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Static size report for the benchmark guest programs.
+
+use std::{collections::BTreeSet, path::Path};
+
+use anyhow::{anyhow, Context, Result};
+use elf::{
+    abi::{SHF_ALLOC, SHF_EXECINSTR, SHF_WRITE, SHT_NOBITS},
+    endian::LittleEndian,
+    ElfBytes,
+};
+use risc0_binfmt::ProgramBinary;
+use serde::Serialize;
+use tabled::{settings::Style, Table, Tabled};
+
+use crate::{display_bytes, Job};
+
+/// Sizes of the allocated sections of a guest's user ELF, along with the
+/// number of pages in its initial memory image (including the kernel).
+#[derive(Serialize, Tabled)]
+pub struct Footprint {
+    pub name: String,
+    #[tabled(display_with = "display_bytes")]
+    pub binary_bytes: usize,
+    #[tabled(display_with = "display_bytes")]
+    pub text_bytes: usize,
+    #[tabled(display_with = "display_bytes")]
+    pub rodata_bytes: usize,
+    #[tabled(display_with = "display_bytes")]
+    pub data_bytes: usize,
+    #[tabled(display_with = "display_bytes")]
+    pub bss_bytes: usize,
+    pub image_pages: usize,
+}
+
+impl Footprint {
+    pub fn new(name: String, binary: &[u8]) -> Result<Self> {
+        let program = ProgramBinary::decode(binary)?;
+        let elf = ElfBytes::<LittleEndian>::minimal_parse(program.user_elf)
+            .map_err(|err| anyhow!("Elf parse error: {err}"))?;
+        let sections = elf
+            .section_headers()
+            .ok_or_else(|| anyhow!("Missing section header table"))?;
+
+        let mut footprint = Self {
+            name,
+            binary_bytes: binary.len(),
+            text_bytes: 0,
+            rodata_bytes: 0,
+            data_bytes: 0,
+            bss_bytes: 0,
+            image_pages: image_pages(&program)?,
+        };
+        for section in sections.iter() {
+            let flags = section.sh_flags;
+            if flags & SHF_ALLOC as u64 == 0 {
+                continue;
+            }
+            let size = section.sh_size as usize;
+            if flags & SHF_EXECINSTR as u64 != 0 {
+                footprint.text_bytes += size;
+            } else if section.sh_type == SHT_NOBITS {
+                footprint.bss_bytes += size;
+            } else if flags & SHF_WRITE as u64 != 0 {
+                footprint.data_bytes += size;
+            } else {
+                footprint.rodata_bytes += size;
+            }
+        }
+        Ok(footprint)
+    }
+}
+
+/// Returns the number of pages in the initial memory image of a guest
+/// binary, which are paged in at startup.
+pub fn binary_image_pages(binary: &[u8]) -> Result<usize> {
+    image_pages(&ProgramBinary::decode(binary)?)
+}
+
+fn image_pages(program: &ProgramBinary) -> Result<usize> {
+    Ok(program.to_image()?.get_page_indexes().len())
+}
+
+/// Reports the footprint of each distinct guest program used by `jobs`.
+pub fn run_footprint(out_path: &Path, jobs: Vec<Job>) -> Result<Vec<Footprint>> {
+    let mut seen = BTreeSet::new();
+    let footprints = jobs
+        .iter()
+        .filter(|job| seen.insert(job.image_id))
+        .map(|job| {
+            // Job names are prefixed with the name of their guest.
+            let name = job.name.split('-').next().unwrap().to_string();
+            Footprint::new(name, &job.elf)
+                .with_context(|| format!("could not inspect the guest of {}", job.name))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut out = csv::WriterBuilder::new().from_path(out_path)?;
+    for footprint in footprints.iter() {
+        out.serialize(footprint)?;
+    }
+    out.flush()?;
+
+    let mut table = Table::new(&footprints);
+    table.with(Style::modern());
+    println!("{table}");
+
+    Ok(footprints)
+}
@@ -0,0 +1,94 @@
+// Copyright 2025 RISC Zero, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Decoding of guest journals back into the values the guests committed, so
+//! that results can be checked and compared value by value.
+
+use std::fmt;
+
+use anyhow::{ensure, Result};
+use risc0_zkvm::{serde::to_vec, sha::Digest, Journal};
+use serde::{de::DeserializeOwned, Serialize};
+
+/// Decodes the journal committed by a benchmark guest.
+pub type Decoder = fn(&Journal) -> Result<DecodedJournal>;
+
+/// The values committed by a guest, as named fields in the order they were
+/// committed.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DecodedJournal {
+    pub fields: Vec<(&'static str, String)>,
+}
+
+impl DecodedJournal {
+    /// Appends the field `name`, formatted with its `Display` impl.
+    pub fn with(mut self, name: &'static str, value: impl fmt::Display) -> Self {
+        self.fields.push((name, value.to_string()));
+        self
+    }
+}
+
+impl fmt::Display for DecodedJournal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, (name, value)) in self.fields.iter().enumerate() {
+            if i > 0 {
+                f.write_str(" ")?;
+            }
+            write!(f, "{name}={value}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Decodes the whole journal as a `T`, failing if it holds anything else,
+/// such as a value of another type that happens to share a prefix.
+pub fn decode_exact<T: Serialize + DeserializeOwned>(journal: &Journal) -> Result<T> {
+    let value: T = journal.decode()?;
+    let bytes: Vec<u8> = to_vec(&value)?
+        .into_iter()
+        .flat_map(u32::to_le_bytes)
+        .collect();
+    ensure!(
+        bytes == journal.bytes,
+        "journal holds {} bytes, but only {} were decoded",
+        journal.bytes.len(),
+        bytes.len()
+    );
+    Ok(value)
+}
+
+/// Decodes the journal of a guest that commits a single digest, such as the
+/// hash benchmarks.
+pub fn decode_digest(journal: &Journal) -> Result<DecodedJournal> {
+    let digest: Digest = decode_exact(journal)?;
+    Ok(DecodedJournal::default().with("digest", digest))
+}
+
+/// Formats bytes as lowercase hex.
+pub fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_trailing_words() {
+        let words = to_vec(&(7u32, 8u32)).unwrap();
+        let journal = Journal::new(words.iter().flat_map(|w| w.to_le_bytes()).collect());
+        assert_eq!(decode_exact::<(u32, u32)>(&journal).unwrap(), (7, 8));
+        assert!(decode_exact::<u32>(&journal).is_err());
+    }
+}
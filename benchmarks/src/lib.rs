@@ -14,26 +14,51 @@
 
 // This is based on zk-benchmarking: https://github.com/delendum-xyz/zk-benchmarking
 
+pub mod aggregate;
 pub mod benches;
+pub mod expected;
+pub mod footprint;
+pub mod journal;
+pub mod rng;
+pub mod sink;
+pub mod specin;
 
 use std::{
+    collections::BTreeMap,
     path::Path,
     time::{Duration, Instant},
 };
 
+use anyhow::{anyhow, bail, ensure, Context, Result};
 use human_repr::{HumanCount, HumanDuration, HumanThroughput};
+use risc0_benchmark_lib::{
+    checkpoint::{parse_checkpoints, Checkpoint, CHECKPOINT_FD},
+    failure::{GuestFailure, FAILURE_EXIT_CODE},
+};
 use risc0_zkvm::{
-    get_prover_server, sha::Digest, ExecutorEnv, ExecutorImpl, ProverOpts, Session, VerifierContext,
+    get_prover_server, sha::Digest, ExecutorEnv, ExecutorImpl, ExitCode, ProverOpts, Session,
+    VerifierContext,
 };
 use serde::Serialize;
 use serde_with::{serde_as, DurationNanoSeconds};
 use tabled::{settings::Style, Table, Tabled};
 
+use crate::{
+    benches::decode_journal,
+    footprint::binary_image_pages,
+    journal::DecodedJournal,
+    rng::{DefaultRng, RngBackend, SuiteRng},
+    sink::ResultsSink,
+};
+
 #[serde_as]
 #[derive(Serialize, Tabled)]
 pub struct Metrics {
     pub name: String,
     pub size: usize,
+    /// The generator of the job's input.
+    #[tabled(skip)]
+    pub rng: &'static str,
     #[tabled(display_with = "display_speed")]
     pub speed: f32,
     #[serde_as(as = "DurationNanoSeconds")]
@@ -56,6 +81,12 @@ pub struct Metrics {
     pub output_bytes: usize,
     #[tabled(display_with = "display_bytes")]
     pub proof_bytes: usize,
+    /// Pages in the initial memory image of the guest, which are paged in at
+    /// startup.
+    pub image_pages: usize,
+    #[serde(skip)]
+    #[tabled(skip)]
+    pub checkpoints: Vec<Checkpoint>,
 }
 
 fn display_bytes(bytes: &usize) -> String {
@@ -75,10 +106,11 @@ fn display_speed(speed: &f32) -> String {
 }
 
 impl Metrics {
-    pub fn new(name: String, size: usize) -> Self {
+    pub fn new(name: String, size: usize, rng: RngBackend) -> Self {
         Metrics {
             name,
             size,
+            rng: rng.name(),
             exec_duration: Duration::default(),
             proof_duration: Duration::default(),
             total_duration: Duration::default(),
@@ -87,7 +119,9 @@ impl Metrics {
             user_cycles: 0,
             output_bytes: 0,
             proof_bytes: 0,
+            image_pages: 0,
             speed: 0.0,
+            checkpoints: Vec::new(),
         }
     }
 }
@@ -98,6 +132,10 @@ pub struct Job {
     input: Vec<u32>,
     image_id: Digest,
     size: usize,
+    rng: RngBackend,
+    /// Jobs with the same key compute the same result in different ways, so
+    /// they must commit the same journal.
+    cross_check: Option<String>,
 }
 
 impl Job {
@@ -108,29 +146,60 @@ impl Job {
             input,
             image_id,
             size,
+            rng: DefaultRng::BACKEND,
+            cross_check: None,
         }
     }
 
-    fn exec_compute(&self) -> (Session, Duration) {
-        let env = ExecutorEnv::builder()
+    /// Records that the input of the job was drawn from `rng`.
+    pub fn with_rng(self, rng: RngBackend) -> Self {
+        Self { rng, ..self }
+    }
+
+    /// Requires the job to commit the same journal as the other jobs checked
+    /// under `key`.
+    fn with_cross_check(self, key: String) -> Self {
+        Self {
+            cross_check: Some(key),
+            ..self
+        }
+    }
+
+    /// Builds the environment for executing the job, registering the file
+    /// descriptor that guests write checkpoints to. Every execution must use
+    /// this, since a guest that writes to an unregistered descriptor fails.
+    fn executor_env<'a>(&self, checkpoints: &'a mut Vec<u8>) -> Result<ExecutorEnv<'a>> {
+        ExecutorEnv::builder()
             .write_slice(&self.input)
+            .write_fd(CHECKPOINT_FD, checkpoints)
             .build()
-            .unwrap();
-        let mut exec = ExecutorImpl::from_elf(env, &self.elf).unwrap();
+    }
+
+    /// Executes the job, returning the session, the execution time and the
+    /// checkpoints written by the guest. Fails if the guest did not halt
+    /// successfully.
+    fn execute(&self) -> Result<(Session, Duration, Vec<Checkpoint>)> {
+        let mut checkpoints = Vec::new();
+        let env = self.executor_env(&mut checkpoints)?;
+        let mut exec = ExecutorImpl::from_elf(env, &self.elf)?;
         let start = Instant::now();
-        let session = exec.run().unwrap();
+        let session = exec.run()?;
         let elapsed = start.elapsed();
-        (session, elapsed)
+        drop(exec);
+        check_exit(&session)?;
+        Ok((session, elapsed, parse_checkpoints(&checkpoints)))
     }
 
-    fn run(&self) -> Metrics {
-        let mut metrics = Metrics::new(self.name.clone(), self.size);
+    fn run(&self, image_pages: usize) -> Metrics {
+        let mut metrics = Metrics::new(self.name.clone(), self.size, self.rng);
+        metrics.image_pages = image_pages;
 
-        let (session, duration) = self.exec_compute();
+        let (session, duration, checkpoints) = self.execute().unwrap();
 
         metrics.total_cycles = session.total_cycles;
         metrics.user_cycles = session.user_cycles;
         metrics.exec_duration = duration;
+        metrics.checkpoints = checkpoints;
 
         let prover = get_prover_server(&ProverOpts::succinct()).unwrap();
         let ctx = VerifierContext::default();
@@ -150,9 +219,217 @@ impl Job {
 
         metrics
     }
+
+    /// Runs the job end-to-end using the cheapest prover options, checking
+    /// that the journal decodes and that the receipt verifies and carries the
+    /// journal produced by the executor.
+    ///
+    /// In dev mode, the receipt is faked rather than proven, so only the
+    /// execution and the journal are checked.
+    fn selftest(&self) -> Result<DecodedJournal> {
+        let (session, _, _) = self.execute()?;
+        let journal = session
+            .journal
+            .clone()
+            .ok_or_else(|| anyhow!("session produced no journal"))?;
+        let decoded = decode_journal(self.image_id, &journal).context("decoding journal")?;
+
+        let prover = get_prover_server(&ProverOpts::fast())?;
+        let ctx = VerifierContext::default();
+        let receipt = prover.prove_session(&ctx, &session)?.receipt;
+        receipt.verify(self.image_id)?;
+        ensure!(
+            receipt.journal == journal,
+            "receipt journal does not match the executor journal"
+        );
+        Ok(decoded)
+    }
+
+    /// Proves the job once with each of the given prover options, checking
+    /// that every receipt verifies against the image ID and that all of them
+    /// carry the same journal.
+    fn differential(&self, modes: &[(&str, ProverOpts)]) -> Result<()> {
+        let (session, _, _) = self.execute()?;
+
+        let ctx = VerifierContext::default();
+        let mut journals = Vec::new();
+        for (mode, opts) in modes {
+            let receipt = get_prover_server(opts)?
+                .prove_session(&ctx, &session)
+                .with_context(|| format!("proving {mode} receipt"))?
+                .receipt;
+            receipt
+                .verify(self.image_id)
+                .with_context(|| format!("verifying {mode} receipt"))?;
+            journals.push((mode, receipt.journal.bytes));
+        }
+
+        if let Some(((first_mode, first), rest)) = journals.split_first() {
+            for (mode, journal) in rest {
+                ensure!(
+                    journal == first,
+                    "{mode} journal differs from {first_mode} journal"
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// Executes the job twice with identical input, checking that both runs
+    /// commit the same journal and take the same number of cycles.
+    fn audit_determinism(&self) -> Result<()> {
+        let (first, _, _) = self.execute()?;
+        let (second, _, _) = self.execute()?;
+
+        let journal = |session: &Session| session.journal.as_ref().map(|j| j.bytes.clone());
+        ensure!(
+            journal(&first) == journal(&second),
+            "journals differ between runs"
+        );
+        ensure!(
+            first.user_cycles == second.user_cycles,
+            "user cycles differ between runs: {} != {}",
+            first.user_cycles,
+            second.user_cycles
+        );
+        ensure!(
+            first.total_cycles == second.total_cycles,
+            "total cycles differ between runs: {} != {}",
+            first.total_cycles,
+            second.total_cycles
+        );
+        Ok(())
+    }
+}
+
+/// Prints the cycle count at each checkpoint, along with the cycles spent
+/// since the previous one.
+fn print_timeline(checkpoints: &[Checkpoint]) {
+    let mut prev = 0;
+    for checkpoint in checkpoints {
+        println!(
+            "   {:<24} {:>10} (+{})",
+            checkpoint.name,
+            display_cycles(&checkpoint.cycle),
+            display_cycles(&(checkpoint.cycle - prev))
+        );
+        prev = checkpoint.cycle;
+    }
+}
+
+/// Checks that the guest halted successfully, decoding the reason from the
+/// journal if it reported a failure with `guest_assert!`.
+fn check_exit(session: &Session) -> Result<()> {
+    match session.exit_code {
+        ExitCode::Halted(0) => Ok(()),
+        ExitCode::Halted(FAILURE_EXIT_CODE) => {
+            let journal = session
+                .journal
+                .as_ref()
+                .ok_or_else(|| anyhow!("session produced no journal"))?;
+            let failure: GuestFailure = journal.decode()?;
+            bail!("guest failed: {failure}")
+        }
+        exit_code => bail!("guest exited with {exit_code:?}"),
+    }
+}
+
+/// Returns the jobs that the self-test runs out of the jobs of a benchmark:
+/// the first one, along with the jobs cross-checked against it.
+pub fn selftest_jobs(jobs: Vec<Job>) -> Vec<Job> {
+    let mut jobs = jobs.into_iter();
+    let Some(first) = jobs.next() else {
+        return Vec::new();
+    };
+    let variants: Vec<Job> = jobs
+        .filter(|job| first.cross_check.is_some() && job.cross_check == first.cross_check)
+        .collect();
+    std::iter::once(first).chain(variants).collect()
+}
+
+/// Runs every job once with minimal proving, reporting each failure, and
+/// checks that jobs with the same cross-check key commit the same values.
+///
+/// Returns `true` if all jobs passed.
+pub fn run_selftest(jobs: Vec<Job>) -> bool {
+    // The first journal decoded under each cross-check key, along with the
+    // job that committed it.
+    let mut checked: BTreeMap<String, (String, DecodedJournal)> = BTreeMap::new();
+    check_jobs("selftest", jobs, |job| {
+        let journal = job.selftest()?;
+        if let Some(key) = &job.cross_check {
+            match checked.get(key) {
+                Some((name, expected)) => ensure!(
+                    &journal == expected,
+                    "journal ({journal}) differs from that of {name} ({expected})"
+                ),
+                None => {
+                    checked.insert(key.clone(), (job.name.clone(), journal));
+                }
+            }
+        }
+        Ok(())
+    })
 }
 
-pub fn run_jobs(out_path: &Path, jobs: Vec<Job>) -> Vec<Metrics> {
+/// Executes every job twice and reports any job whose journal or cycle counts
+/// differ between the two runs.
+///
+/// Returns `true` if all jobs were deterministic.
+pub fn run_determinism_audit(jobs: Vec<Job>) -> bool {
+    check_jobs("determinism audit", jobs, Job::audit_determinism)
+}
+
+/// Proves every job as a composite and a succinct receipt, and optionally a
+/// Groth16 receipt, reporting any job whose receipts fail to verify or carry
+/// different journals.
+///
+/// Returns `true` if all jobs passed.
+pub fn run_differential(jobs: Vec<Job>, groth16: bool) -> bool {
+    let mut modes = vec![
+        ("composite", ProverOpts::composite()),
+        ("succinct", ProverOpts::succinct()),
+    ];
+    if groth16 {
+        modes.push(("groth16", ProverOpts::groth16()));
+    }
+    check_jobs("differential proving", jobs, |job| job.differential(&modes))
+}
+
+fn check_jobs(label: &str, jobs: Vec<Job>, mut check: impl FnMut(&Job) -> Result<()>) -> bool {
+    tracing::info!("Running {label} over {} jobs", jobs.len());
+
+    let start = Instant::now();
+    let mut failures = 0;
+    for job in jobs {
+        match check(&job) {
+            Ok(()) => println!("ok      {}", job.name),
+            Err(err) => {
+                println!("FAILED  {}: {err:#}", job.name);
+                failures += 1;
+            }
+        }
+    }
+
+    println!(
+        "{label} finished in {}: {failures} failed",
+        start.elapsed().human_duration()
+    );
+    failures == 0
+}
+
+/// Runs every job, writing the metrics to a CSV file at `out_path` and
+/// forwarding them to each of `sinks` as the jobs complete.
+///
+/// A sink that fails to record a result is reported but does not stop the run.
+/// The run pauses for `cooldown` between jobs, so that a machine that throttles
+/// under sustained load starts each job from the same state.
+pub fn run_jobs(
+    out_path: &Path,
+    jobs: Vec<Job>,
+    sinks: &mut [Box<dyn ResultsSink>],
+    cooldown: Duration,
+) -> Vec<Metrics> {
     tracing::info!("");
     tracing::info!(
         "Running {} jobs; saving output to {}",
@@ -163,14 +440,29 @@ pub fn run_jobs(out_path: &Path, jobs: Vec<Job>) -> Vec<Metrics> {
     let mut out = csv::WriterBuilder::new().from_path(out_path).unwrap();
 
     let mut all_metrics = Vec::new();
+    // Jobs of the same benchmark share a guest, so its image is only built once.
+    let mut image_pages = BTreeMap::new();
 
-    for job in jobs {
+    for (i, job) in jobs.into_iter().enumerate() {
+        if i > 0 && !cooldown.is_zero() {
+            tracing::info!("Cooling down for {}", cooldown.human_duration());
+            std::thread::sleep(cooldown);
+        }
         println!("Benchmarking {}", job.name);
 
-        let metrics = job.run();
+        let pages = *image_pages
+            .entry(job.image_id)
+            .or_insert_with(|| binary_image_pages(&job.elf).unwrap());
+        let metrics = job.run(pages);
         println!(" + {}", display_speed(&metrics.speed));
+        print_timeline(&metrics.checkpoints);
         out.serialize(&metrics).expect("Could not serialize");
         out.flush().expect("Could not flush");
+        for sink in sinks.iter_mut() {
+            if let Err(err) = sink.record(&metrics) {
+                tracing::warn!("Could not record {} in results sink: {err:#}", metrics.name);
+            }
+        }
 
         all_metrics.push(metrics);
     }
@@ -184,3 +476,24 @@ pub fn run_jobs(out_path: &Path, jobs: Vec<Job>) -> Vec<Metrics> {
 
     all_metrics
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        benches::{maxflow, sort},
+        rng::DefaultRng,
+    };
+
+    // These guests write checkpoints, which fail unless the execution path
+    // registers the checkpoint file descriptor.
+    #[test]
+    #[ignore = "proves both guests; run with RISC0_DEV_MODE=1 to fake the receipts"]
+    fn selftest_instrumented_guests() {
+        for jobs in [
+            maxflow::new_jobs::<DefaultRng>(),
+            sort::new_jobs::<DefaultRng>(),
+        ] {
+            jobs[0].selftest().unwrap();
+        }
+    }
+}
@@ -14,22 +14,157 @@
 
 // This is based on zk-benchmarking: https://github.com/delendum-xyz/zk-benchmarking
 
-use std::path::PathBuf;
+use std::{path::PathBuf, time::Duration};
 
-use clap::{Parser, Subcommand};
+use anyhow::{ensure, Context, Result};
+use clap::{
+    error::ErrorKind, parser::ValueSource, ArgGroup, ArgMatches, CommandFactory, FromArgMatches,
+    Parser, Subcommand,
+};
 use enum_iterator::Sequence;
-use risc0_benchmark::{benches::*, run_jobs, Job};
+#[cfg(feature = "sqlite")]
+use risc0_benchmark::sink::{run_query, MetricsQuery, SqliteSink};
+use risc0_benchmark::{
+    aggregate::run_aggregate,
+    benches::*,
+    expected::{check_expected, record_expected},
+    footprint::run_footprint,
+    rng::{ChaCha12Rng, DefaultRng, RngBackend, SuiteRng, Xoshiro256PlusPlus},
+    run_determinism_audit, run_differential, run_jobs, run_selftest, selftest_jobs,
+    sink::{HttpSink, ResultsSink},
+    specin::{save_inputs, InputFile},
+    Job,
+};
 use tracing_subscriber::EnvFilter;
 
 #[derive(Parser)]
 #[command(author, version, about, long_about = None)]
+// Each mode replaces benchmarking, so at most one can be given, and none of
+// them can be combined with the flags that only affect a benchmarking run.
+#[command(group(ArgGroup::new("mode").args([
+    "save_inputs",
+    "aggregate",
+    "record_expected",
+    "check_expected",
+    "differential",
+    "aggregate",
+    "differential",
+    "aggregate",
+    "audit_determinism",
+])))]
 struct Cli {
-    // CSV output file
-    #[arg(long, value_name = "FILE", default_value = "metrics.csv")]
-    out: PathBuf,
+    // CSV output file [default: metrics.csv, or footprint.csv for footprint]
+    #[arg(long, value_name = "FILE", conflicts_with = "mode")]
+    out: Option<PathBuf>,
+
+    /// Also POST the metrics of each job as JSON to this URL
+    #[arg(long, value_name = "URL", conflicts_with = "mode")]
+    post_url: Option<String>,
+
+    /// Also append the metrics of each job to a SQLite database
+    #[cfg(feature = "sqlite")]
+    #[arg(long, value_name = "FILE", conflicts_with = "mode")]
+    sqlite: Option<PathBuf>,
+
+    /// Execute each job twice and check that the journals and cycle counts
+    /// match, instead of benchmarking
+    #[arg(long)]
+    audit_determinism: bool,
+
+    /// Prove each job as a composite and a succinct receipt and check that the
+    /// journals match, instead of benchmarking
+    #[arg(long)]
+    differential: bool,
+
+    /// Also prove a Groth16 receipt with --differential
+    #[arg(long, requires = "differential")]
+    groth16: bool,
+
+    /// Prove each job and then a single receipt that verifies all of them,
+    /// writing it as JSON to FILE, instead of benchmarking
+    #[arg(long, value_name = "FILE")]
+    aggregate: Option<PathBuf>,
+
+    /// Public randomness beacon value whose digest the aggregate receipt
+    /// commits, showing that it was proven after the value was published
+    #[arg(long, value_name = "VALUE", requires = "aggregate")]
+    beacon: Option<String>,
+
+    /// Execute each job and record the decoded values of its journal in FILE,
+    /// instead of benchmarking
+    #[arg(long, value_name = "FILE")]
+    record_expected: Option<PathBuf>,
+
+    /// Execute each job and check the decoded values of its journal against
+    /// FILE, instead of benchmarking
+    #[arg(long, value_name = "FILE")]
+    check_expected: Option<PathBuf>,
+
+    /// Write the input of each job to DIR as a .specin file, instead of
+    /// benchmarking
+    #[arg(long, value_name = "DIR")]
+    save_inputs: Option<PathBuf>,
+
+    /// Run the job saved in a .specin file, instead of the jobs of the command
+    #[arg(long, value_name = "FILE")]
+    replay: Option<PathBuf>,
+
+    /// Generator of the synthetic inputs, such as `xoshiro256pp-v1`
+    #[arg(
+        long,
+        value_name = "VERSION",
+        default_value_t = DefaultRng::BACKEND,
+        conflicts_with = "replay"
+    )]
+    rng: RngBackend,
+
+    /// Seconds to pause between jobs, to keep thermal throttling from skewing
+    /// the timings of later jobs
+    #[arg(
+        long,
+        value_name = "SECONDS",
+        default_value_t = 0,
+        conflicts_with = "mode"
+    )]
+    cooldown: u64,
 
     #[command(subcommand)]
-    command: Option<Command>,
+    command: Option<CliCommand>,
+}
+
+#[derive(Subcommand)]
+enum CliCommand {
+    #[command(flatten)]
+    Bench(Command),
+    /// Show the recorded results with the shortest proving time, from a
+    /// database written with --sqlite.
+    #[cfg(feature = "sqlite")]
+    Query(QueryArgs),
+}
+
+#[cfg(feature = "sqlite")]
+#[derive(clap::Args)]
+struct QueryArgs {
+    /// SQLite database written with --sqlite
+    #[arg(value_name = "FILE")]
+    database: PathBuf,
+
+    /// Only show jobs of this workload, such as `maxflow` or `sort-radix`
+    #[arg(long)]
+    workload: Option<String>,
+
+    /// Only show jobs of this size
+    #[arg(long)]
+    size: Option<usize>,
+
+    /// Only show results whose inputs came from this generator version, such
+    /// as `chacha12-v1`
+    #[arg(long, value_name = "VERSION")]
+    rng: Option<String>,
+
+    /// Show at most this many results
+    #[arg(long, default_value_t = 10)]
+    limit: usize,
 }
 
 #[derive(Eq, PartialEq, Subcommand, Sequence)]
@@ -39,37 +174,127 @@ enum Command {
     BigBlake3,
     BigKeccak,
     BigSha2,
+    Btree,
+    EcdsaBatch,
     EcdsaVerify,
     Ed25519Verify,
     Fibonacci,
+    Gemm,
+    Imgdec,
     IterBlake2b,
     IterBlake3,
     IterKeccak,
     IterSha2,
+    Json,
+    Maxflow,
     Membership,
+    Merkle,
+    Montecarlo,
+    RsaModpow,
+    Sort,
     Sudoku,
+    /// Run the smallest job of every benchmark in dev mode and decode its
+    /// journal.
+    Selftest,
+    /// Report the static size of every benchmark guest.
+    Footprint,
 }
 
 impl Command {
-    fn get_jobs(&self) -> Vec<Job> {
-        match self {
-            Command::All => enum_iterator::all::<Command>()
-                .skip_while(|x| *x == Command::All)
-                .flat_map(|x| x.get_jobs())
-                .collect(),
-            Command::BigBlake2b => big_blake2b::new_jobs(),
-            Command::BigBlake3 => big_blake3::new_jobs(),
-            Command::BigKeccak => big_keccak::new_jobs(),
-            Command::BigSha2 => big_sha2::new_jobs(),
-            Command::EcdsaVerify => ecdsa_verify::new_jobs(),
-            Command::Ed25519Verify => ed25519_verify::new_jobs(),
+    /// Generates the jobs of the command, drawing their inputs from `rng`.
+    fn jobs(&self, rng: RngBackend) -> Vec<Job> {
+        match rng {
+            RngBackend::ChaCha12 => self.get_jobs::<ChaCha12Rng>(),
+            RngBackend::Xoshiro256PlusPlus => self.get_jobs::<Xoshiro256PlusPlus>(),
+        }
+    }
+
+    fn get_jobs<R: SuiteRng>(&self) -> Vec<Job> {
+        let jobs = match self {
+            Command::All => Self::benchmarks().flat_map(|x| x.get_jobs::<R>()).collect(),
+            Command::BigBlake2b => big_blake2b::new_jobs::<R>(),
+            Command::BigBlake3 => big_blake3::new_jobs::<R>(),
+            Command::BigKeccak => big_keccak::new_jobs::<R>(),
+            Command::BigSha2 => big_sha2::new_jobs::<R>(),
+            Command::Btree => btree::new_jobs::<R>(),
+            Command::EcdsaBatch => ecdsa_batch::new_jobs::<R>(),
+            Command::EcdsaVerify => ecdsa_verify::new_jobs::<R>(),
+            Command::Ed25519Verify => ed25519_verify::new_jobs::<R>(),
             Command::Fibonacci => fibonacci::new_jobs(),
+            Command::Gemm => gemm::new_jobs::<R>(),
+            Command::Imgdec => imgdec::new_jobs::<R>(),
             Command::IterBlake2b => iter_blake2b::new_jobs(),
             Command::IterBlake3 => iter_blake3::new_jobs(),
             Command::IterKeccak => iter_keccak::new_jobs(),
             Command::IterSha2 => iter_sha2::new_jobs(),
+            Command::Json => json::new_jobs::<R>(),
+            Command::Maxflow => maxflow::new_jobs::<R>(),
             Command::Membership => membership::new_jobs(),
+            Command::Merkle => merkle::new_jobs::<R>(),
+            Command::Montecarlo => montecarlo::new_jobs::<R>(),
+            Command::RsaModpow => rsa_modpow::new_jobs::<R>(),
+            Command::Sort => sort::new_jobs::<R>(),
             Command::Sudoku => sudoku::new_jobs(),
+            Command::Selftest => Self::benchmarks()
+                .flat_map(|x| selftest_jobs(x.get_jobs::<R>()))
+                .collect(),
+            Command::Footprint => Self::benchmarks()
+                .filter_map(|x| x.get_jobs::<R>().into_iter().next())
+                .collect(),
+        };
+        jobs.into_iter()
+            .map(|job| job.with_rng(R::BACKEND))
+            .collect()
+    }
+
+    fn benchmarks() -> impl Iterator<Item = Command> {
+        enum_iterator::all::<Command>()
+            .filter(|x| !matches!(x, Command::All | Command::Selftest | Command::Footprint))
+    }
+}
+
+/// The flags that only affect the benchmark commands.
+const RUN_ONLY: &[&str] = &[
+    "post_url",
+    "audit_determinism",
+    "save_inputs",
+    "replay",
+    "sqlite",
+    "cooldown",
+    "record_expected",
+    "check_expected",
+    "differential",
+    "aggregate",
+];
+
+/// Returns whether `subcommand` ignores the flag `id`, which is then rejected
+/// rather than silently dropped.
+fn ignores(subcommand: &str, id: &str) -> bool {
+    match subcommand {
+        "selftest" => RUN_ONLY.contains(&id) || id == "out",
+        "footprint" => RUN_ONLY.contains(&id),
+        // The query takes its own --rng filter, after the subcommand.
+        "query" => RUN_ONLY.contains(&id) || ["out", "rng"].contains(&id),
+        _ => false,
+    }
+}
+
+/// Exits with an error if a flag was given that the subcommand ignores.
+fn reject_ignored_flags(matches: &ArgMatches) {
+    let Some(subcommand) = matches.subcommand_name() else {
+        return;
+    };
+    for id in matches.ids().map(|id| id.as_str()) {
+        if ignores(subcommand, id) && matches.value_source(id) == Some(ValueSource::CommandLine) {
+            let flag = id.replace('_', "-");
+            Cli::command()
+                .error(
+                    ErrorKind::ArgumentConflict,
+                    format!(
+                        "the argument '--{flag}' cannot be used with the '{subcommand}' subcommand"
+                    ),
+                )
+                .exit();
         }
     }
 }
@@ -80,10 +305,92 @@ fn init_logging() {
         .init();
 }
 
-fn main() {
+fn main() -> Result<()> {
     init_logging();
 
-    let cli = Cli::parse();
-    let cmd = cli.command.unwrap_or(Command::All);
-    run_jobs(&cli.out, cmd.get_jobs());
+    let matches = Cli::command().get_matches();
+    reject_ignored_flags(&matches);
+    let cli = Cli::from_arg_matches(&matches).unwrap_or_else(|err| err.exit());
+    let cmd = match cli.command {
+        Some(CliCommand::Bench(cmd)) => cmd,
+        #[cfg(feature = "sqlite")]
+        Some(CliCommand::Query(args)) => {
+            let query = MetricsQuery {
+                workload: args.workload,
+                size: args.size,
+                rng: args.rng,
+                limit: args.limit,
+            };
+            run_query(&args.database, &query).context("could not query the results")?;
+            return Ok(());
+        }
+        None => Command::All,
+    };
+    match cmd {
+        Command::Selftest => {
+            // Fake the receipts unless dev mode was set explicitly, so that the
+            // self-test takes seconds; RISC0_DEV_MODE=0 proves them for real.
+            if std::env::var_os("RISC0_DEV_MODE").is_none() {
+                std::env::set_var("RISC0_DEV_MODE", "1");
+            }
+            ensure!(run_selftest(cmd.jobs(cli.rng)), "self-test failed");
+        }
+        Command::Footprint => {
+            let out = cli.out.unwrap_or_else(|| "footprint.csv".into());
+            run_footprint(&out, cmd.jobs(cli.rng))
+                .with_context(|| format!("could not write {}", out.display()))?;
+        }
+        _ => {
+            let jobs = match &cli.replay {
+                Some(path) => vec![InputFile::load(path)
+                    .and_then(InputFile::into_job)
+                    .with_context(|| format!("could not replay {}", path.display()))?],
+                None => cmd.jobs(cli.rng),
+            };
+
+            if let Some(dir) = &cli.save_inputs {
+                save_inputs(dir, &jobs)
+                    .with_context(|| format!("could not save inputs to {}", dir.display()))?;
+            } else if let Some(path) = &cli.aggregate {
+                run_aggregate(path, jobs, cli.beacon.as_deref())
+                    .context("could not aggregate the jobs")?;
+            } else if let Some(path) = &cli.record_expected {
+                let recorded = record_expected(path, jobs)
+                    .with_context(|| format!("could not record {}", path.display()))?;
+                ensure!(recorded, "some jobs failed and were not recorded");
+            } else if let Some(path) = &cli.check_expected {
+                let passed = check_expected(path, jobs)
+                    .with_context(|| format!("could not check {}", path.display()))?;
+                ensure!(passed, "some jobs did not match {}", path.display());
+            } else if cli.differential {
+                ensure!(
+                    run_differential(jobs, cli.groth16),
+                    "some proof modes disagree"
+                );
+            } else if cli.audit_determinism {
+                ensure!(
+                    run_determinism_audit(jobs),
+                    "some jobs are nondeterministic"
+                );
+            } else {
+                let mut sinks: Vec<Box<dyn ResultsSink>> = Vec::new();
+                if let Some(url) = cli.post_url {
+                    sinks.push(Box::new(HttpSink::new(url)));
+                }
+                #[cfg(feature = "sqlite")]
+                if let Some(path) = &cli.sqlite {
+                    let sink = SqliteSink::open(path)
+                        .with_context(|| format!("could not open {}", path.display()))?;
+                    sinks.push(Box::new(sink));
+                }
+                run_jobs(
+                    &cli.out.unwrap_or_else(|| "metrics.csv".into()),
+                    jobs,
+                    &mut sinks,
+                    Duration::from_secs(cli.cooldown),
+                );
+            }
+        }
+    }
+    Ok(())
 }
@@ -0,0 +1,42 @@
+// Copyright 2025 RISC Zero, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Random number generation for synthetic benchmark inputs.
+//!
+//! Every generator, including the ones that create signing keys, is generic
+//! over a [SuiteRng] and draws from [suite_rng], and seeded parameters use
+//! [SEED]. The inputs of every job, and therefore their journals, only change
+//! when a different backend is selected with `--rng`, or when a backend's
+//! output stream changes along with its name, rather than whenever `rand`
+//! changes what `StdRng` means.
+
+use rand::RngCore;
+pub use risc0_benchmark_lib::rng::{
+    ChaCha12Rng, DefaultRng, RngBackend, SuiteRng, Xoshiro256PlusPlus,
+};
+
+/// The seed of every generator.
+pub const SEED: u64 = 1337;
+
+/// Returns an `R` seeded with [SEED].
+pub fn suite_rng<R: SuiteRng>() -> R {
+    R::seed_from_u64(SEED)
+}
+
+/// Draws 32 bytes from the generator, from which to derive a signing key.
+pub fn key_bytes(rand: &mut impl RngCore) -> [u8; 32] {
+    let mut bytes = [0u8; 32];
+    rand.fill_bytes(&mut bytes);
+    bytes
+}
@@ -0,0 +1,198 @@
+// Copyright 2025 RISC Zero, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use anyhow::Result;
+
+use crate::Metrics;
+
+/// A destination that receives the metrics of each job as soon as it
+/// completes.
+pub trait ResultsSink {
+    fn record(&mut self, metrics: &Metrics) -> Result<()>;
+}
+
+/// Posts the metrics of each job as JSON to an HTTP endpoint.
+pub struct HttpSink {
+    client: reqwest::blocking::Client,
+    url: String,
+}
+
+impl HttpSink {
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            client: reqwest::blocking::Client::new(),
+            url: url.into(),
+        }
+    }
+}
+
+impl ResultsSink for HttpSink {
+    fn record(&mut self, metrics: &Metrics) -> Result<()> {
+        self.client
+            .post(&self.url)
+            .json(metrics)
+            .send()?
+            .error_for_status()?;
+        Ok(())
+    }
+}
+
+/// Appends the metrics of each job to the `metrics` table of a SQLite
+/// database, creating the table if needed.
+#[cfg(feature = "sqlite")]
+pub struct SqliteSink {
+    conn: rusqlite::Connection,
+}
+
+#[cfg(feature = "sqlite")]
+impl SqliteSink {
+    pub fn open(path: &std::path::Path) -> Result<Self> {
+        let conn = rusqlite::Connection::open(path)?;
+        conn.execute_batch(
+            "CREATE TABLE IF NOT EXISTS metrics (
+                recorded_at INTEGER NOT NULL,
+                rng TEXT NOT NULL,
+                name TEXT NOT NULL,
+                size INTEGER NOT NULL,
+                speed REAL NOT NULL,
+                exec_ns INTEGER NOT NULL,
+                proof_ns INTEGER NOT NULL,
+                total_ns INTEGER NOT NULL,
+                verify_ns INTEGER NOT NULL,
+                total_cycles INTEGER NOT NULL,
+                user_cycles INTEGER NOT NULL,
+                output_bytes INTEGER NOT NULL,
+                proof_bytes INTEGER NOT NULL,
+                image_pages INTEGER NOT NULL
+            )",
+        )?;
+        Ok(Self { conn })
+    }
+}
+
+#[cfg(feature = "sqlite")]
+impl ResultsSink for SqliteSink {
+    fn record(&mut self, metrics: &Metrics) -> Result<()> {
+        use std::time::{SystemTime, UNIX_EPOCH};
+
+        let recorded_at = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs() as i64;
+        self.conn.execute(
+            "INSERT INTO metrics VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14)",
+            rusqlite::params![
+                recorded_at,
+                metrics.rng,
+                metrics.name,
+                metrics.size as i64,
+                metrics.speed as f64,
+                metrics.exec_duration.as_nanos() as i64,
+                metrics.proof_duration.as_nanos() as i64,
+                metrics.total_duration.as_nanos() as i64,
+                metrics.verify_duration.as_nanos() as i64,
+                metrics.total_cycles as i64,
+                metrics.user_cycles as i64,
+                metrics.output_bytes as i64,
+                metrics.proof_bytes as i64,
+                metrics.image_pages as i64,
+            ],
+        )?;
+        Ok(())
+    }
+}
+
+/// Filters for reading back the results recorded by a [SqliteSink].
+#[cfg(feature = "sqlite")]
+#[derive(Debug, Clone, Default)]
+pub struct MetricsQuery {
+    /// Only results of jobs of this workload, such as `maxflow`, or of a
+    /// variant of it, such as `sort-radix`.
+    pub workload: Option<String>,
+    /// Only results of jobs of this size.
+    pub size: Option<usize>,
+    /// Only results whose inputs came from this generator, such as
+    /// `chacha12-v1`.
+    pub rng: Option<String>,
+    /// The largest number of results to return.
+    pub limit: usize,
+}
+
+/// A result read back from a SQLite database by [SqliteSink::query].
+#[cfg(feature = "sqlite")]
+#[derive(tabled::Tabled)]
+pub struct StoredMetrics {
+    pub name: String,
+    pub size: usize,
+    pub rng: String,
+    /// Seconds since the Unix epoch.
+    pub recorded_at: i64,
+    #[tabled(display_with = "crate::display_duration")]
+    pub exec_duration: std::time::Duration,
+    #[tabled(display_with = "crate::display_duration")]
+    pub proof_duration: std::time::Duration,
+    #[tabled(display_with = "crate::display_cycles")]
+    pub total_cycles: u64,
+}
+
+#[cfg(feature = "sqlite")]
+impl SqliteSink {
+    /// Returns the recorded results that match `query`, with the shortest
+    /// proving time first.
+    pub fn query(&self, query: &MetricsQuery) -> Result<Vec<StoredMetrics>> {
+        use std::time::Duration;
+
+        let mut stmt = self.conn.prepare(
+            "SELECT name, size, rng, recorded_at, exec_ns, proof_ns, total_cycles
+            FROM metrics
+            WHERE (?1 IS NULL OR name = ?1 OR substr(name, 1, length(?1) + 1) = ?1 || '-')
+                AND (?2 IS NULL OR size = ?2)
+                AND (?3 IS NULL OR rng = ?3)
+            ORDER BY proof_ns
+            LIMIT ?4",
+        )?;
+        let rows = stmt.query_map(
+            rusqlite::params![
+                query.workload,
+                query.size.map(|x| x as i64),
+                query.rng,
+                query.limit as i64,
+            ],
+            |row| {
+                Ok(StoredMetrics {
+                    name: row.get(0)?,
+                    size: row.get::<_, i64>(1)? as usize,
+                    rng: row.get(2)?,
+                    recorded_at: row.get(3)?,
+                    exec_duration: Duration::from_nanos(row.get::<_, i64>(4)? as u64),
+                    proof_duration: Duration::from_nanos(row.get::<_, i64>(5)? as u64),
+                    total_cycles: row.get::<_, i64>(6)? as u64,
+                })
+            },
+        )?;
+        Ok(rows.collect::<Result<_, _>>()?)
+    }
+}
+
+/// Prints the results recorded in the SQLite database at `path` that match
+/// `query`, with the shortest proving time first.
+#[cfg(feature = "sqlite")]
+pub fn run_query(path: &std::path::Path, query: &MetricsQuery) -> Result<Vec<StoredMetrics>> {
+    use tabled::{settings::Style, Table};
+
+    anyhow::ensure!(path.exists(), "no database at {}", path.display());
+    let rows = SqliteSink::open(path)?.query(query)?;
+    let mut table = Table::new(&rows);
+    table.with(Style::modern());
+    println!("{table}");
+
+    Ok(rows)
+}
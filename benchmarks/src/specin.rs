@@ -0,0 +1,254 @@
+// Copyright 2025 RISC Zero, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The `.specin` container, which holds the exact input of a job so that it
+//! can be saved, shared and replayed as a single file.
+//!
+//! All integers are little-endian. A file consists of:
+//!
+//! * the magic bytes `SPECIN\0\0` and a `u32` version;
+//! * the workload id, as a `u32` length followed by UTF-8 bytes;
+//! * the 32-byte image ID of the guest;
+//! * a `u32` count of parameters, each a key and a value encoded like the
+//!   workload id;
+//! * a `u32` count of payload blobs, each a `u64` length followed by bytes.
+//!
+//! The first blob holds the words written to the guest's stdin, so guests read
+//! a replayed input exactly as they would read the original.
+
+use std::{
+    collections::BTreeMap,
+    fs::File,
+    io::{BufReader, BufWriter, Read, Write},
+    path::Path,
+};
+
+use anyhow::{anyhow, bail, ensure, Context, Result};
+use risc0_zkvm::sha::Digest;
+
+use crate::{benches::guest_elf, rng::RngBackend, Job};
+
+const MAGIC: &[u8; 8] = b"SPECIN\0\0";
+const VERSION: u32 = 1;
+
+/// The input of a job, as stored in a `.specin` file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InputFile {
+    pub workload: String,
+    pub image_id: Digest,
+    pub params: BTreeMap<String, String>,
+    pub blobs: Vec<Vec<u8>>,
+}
+
+impl InputFile {
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        self.write_to(&mut writer)?;
+        writer.flush()?;
+        Ok(())
+    }
+
+    pub fn load(path: &Path) -> Result<Self> {
+        let mut reader = BufReader::new(File::open(path)?);
+        Self::read_from(&mut reader).with_context(|| format!("reading {}", path.display()))
+    }
+
+    pub fn write_to(&self, w: &mut impl Write) -> Result<()> {
+        w.write_all(MAGIC)?;
+        w.write_all(&VERSION.to_le_bytes())?;
+        write_str(w, &self.workload)?;
+        w.write_all(self.image_id.as_bytes())?;
+        w.write_all(&(self.params.len() as u32).to_le_bytes())?;
+        for (key, value) in &self.params {
+            write_str(w, key)?;
+            write_str(w, value)?;
+        }
+        w.write_all(&(self.blobs.len() as u32).to_le_bytes())?;
+        for blob in &self.blobs {
+            w.write_all(&(blob.len() as u64).to_le_bytes())?;
+            w.write_all(blob)?;
+        }
+        Ok(())
+    }
+
+    pub fn read_from(r: &mut impl Read) -> Result<Self> {
+        let mut magic = [0u8; 8];
+        r.read_exact(&mut magic)?;
+        ensure!(&magic == MAGIC, "not a .specin file");
+        let version = read_u32(r)?;
+        ensure!(version == VERSION, "unsupported .specin version {version}");
+
+        let workload = read_str(r)?;
+        let mut image_id = [0u8; 32];
+        r.read_exact(&mut image_id)?;
+
+        let mut params = BTreeMap::new();
+        for _ in 0..read_u32(r)? {
+            let key = read_str(r)?;
+            let value = read_str(r)?;
+            params.insert(key, value);
+        }
+
+        let mut blobs = Vec::new();
+        for _ in 0..read_u32(r)? {
+            let len = read_u64(r)?;
+            let mut blob = Vec::new();
+            r.take(len).read_to_end(&mut blob)?;
+            ensure!(blob.len() as u64 == len, "truncated payload blob");
+            blobs.push(blob);
+        }
+
+        Ok(Self {
+            workload,
+            image_id: Digest::from_bytes(image_id),
+            params,
+            blobs,
+        })
+    }
+
+    /// Rebuilds the job this input was saved from, taking the guest from the
+    /// benchmark with the same image ID.
+    pub fn into_job(self) -> Result<Job> {
+        let elf = guest_elf(self.image_id)
+            .ok_or_else(|| anyhow!("no guest with image ID {}", self.image_id))?;
+        let size = match self.params.get("size") {
+            Some(size) => size.parse().context("invalid size parameter")?,
+            None => bail!("missing size parameter"),
+        };
+        let rng: RngBackend = match self.params.get("rng") {
+            Some(rng) => rng.parse().map_err(anyhow::Error::msg)?,
+            None => bail!("missing rng parameter"),
+        };
+        let [payload] = <[Vec<u8>; 1]>::try_from(self.blobs)
+            .map_err(|blobs| anyhow!("expected 1 payload blob, found {}", blobs.len()))?;
+        ensure!(
+            payload.len() % 4 == 0,
+            "payload is not a whole number of words"
+        );
+        let input = payload
+            .chunks_exact(4)
+            .map(|word| u32::from_le_bytes(word.try_into().unwrap()))
+            .collect();
+
+        Ok(Job::new(self.workload, elf, self.image_id, input, size).with_rng(rng))
+    }
+}
+
+impl From<&Job> for InputFile {
+    fn from(job: &Job) -> Self {
+        Self {
+            workload: job.name.clone(),
+            image_id: job.image_id,
+            params: BTreeMap::from([
+                ("rng".to_string(), job.rng.name().to_string()),
+                ("size".to_string(), job.size.to_string()),
+            ]),
+            blobs: vec![job.input.iter().flat_map(|x| x.to_le_bytes()).collect()],
+        }
+    }
+}
+
+/// Writes the input of each job to `<dir>/<name>.specin`.
+pub fn save_inputs(dir: &Path, jobs: &[Job]) -> Result<()> {
+    std::fs::create_dir_all(dir)?;
+    for job in jobs {
+        let path = dir.join(format!("{}.specin", job.name));
+        InputFile::from(job).save(&path)?;
+        println!("Saved {}", path.display());
+    }
+    Ok(())
+}
+
+fn write_str(w: &mut impl Write, s: &str) -> Result<()> {
+    w.write_all(&(s.len() as u32).to_le_bytes())?;
+    w.write_all(s.as_bytes())?;
+    Ok(())
+}
+
+fn read_u32(r: &mut impl Read) -> Result<u32> {
+    let mut bytes = [0u8; 4];
+    r.read_exact(&mut bytes)?;
+    Ok(u32::from_le_bytes(bytes))
+}
+
+fn read_u64(r: &mut impl Read) -> Result<u64> {
+    let mut bytes = [0u8; 8];
+    r.read_exact(&mut bytes)?;
+    Ok(u64::from_le_bytes(bytes))
+}
+
+fn read_str(r: &mut impl Read) -> Result<String> {
+    let len = read_u32(r)?;
+    let mut bytes = Vec::new();
+    r.take(len as u64).read_to_end(&mut bytes)?;
+    ensure!(bytes.len() == len as usize, "truncated string");
+    Ok(String::from_utf8(bytes)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> InputFile {
+        InputFile {
+            workload: "sort-radix-1000".to_string(),
+            image_id: Digest::from([1, 2, 3, 4, 5, 6, 7, 8]),
+            params: BTreeMap::from([
+                ("rng".to_string(), "chacha12-v1".to_string()),
+                ("size".to_string(), "1000".to_string()),
+            ]),
+            blobs: vec![vec![1, 2, 3, 4], vec![]],
+        }
+    }
+
+    fn encode(input: &InputFile) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        input.write_to(&mut bytes).unwrap();
+        bytes
+    }
+
+    fn decode(bytes: &[u8]) -> Result<InputFile> {
+        InputFile::read_from(&mut &bytes[..])
+    }
+
+    #[test]
+    fn round_trip() {
+        let input = sample();
+        assert_eq!(decode(&encode(&input)).unwrap(), input);
+    }
+
+    #[test]
+    fn rejects_malformed_files() {
+        let bytes = encode(&sample());
+        let error = |bytes: &[u8]| decode(bytes).unwrap_err().to_string();
+
+        let mut bad_magic = bytes.clone();
+        bad_magic[0] = b'X';
+        assert_eq!(error(&bad_magic), "not a .specin file");
+
+        let mut bad_version = bytes.clone();
+        bad_version[8..12].copy_from_slice(&2u32.to_le_bytes());
+        assert_eq!(error(&bad_version), "unsupported .specin version 2");
+
+        // Cut the file in the middle of the workload id.
+        assert_eq!(error(&bytes[..16 + 4]), "truncated string");
+
+        // Cut the file one byte short of the end of the first blob, whose
+        // length is followed by 4 bytes of payload and an empty blob.
+        assert_eq!(
+            error(&bytes[..bytes.len() - 8 - 1]),
+            "truncated payload blob"
+        );
+    }
+}